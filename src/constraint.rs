@@ -0,0 +1,284 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::expression::LinearExpression;
+
+/// The tolerance used when comparing the left and the right hand side of a constraint.
+const EPSILON: f64 = 1e-9;
+
+/// The relation between the left and the right hand side of a constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    /// The left hand side is less than or equal to the right hand side.
+    LessThanOrEqual,
+
+    /// The left hand side is greater than or equal to the right hand side.
+    GreaterThanOrEqual,
+
+    /// The left hand side is equal to the right hand side.
+    Equal,
+}
+
+impl Relation {
+    /// Get the reversed relation.
+    ///
+    /// # Returns
+    /// The relation that results from multiplying both sides with a negative number.
+    pub fn reversed(&self) -> Relation {
+        match self {
+            Relation::LessThanOrEqual => Relation::GreaterThanOrEqual,
+            Relation::GreaterThanOrEqual => Relation::LessThanOrEqual,
+            Relation::Equal => Relation::Equal,
+        }
+    }
+}
+
+/// Implement the Display trait for Relation.
+impl Display for Relation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Relation::LessThanOrEqual => write!(f, "<="),
+            Relation::GreaterThanOrEqual => write!(f, ">="),
+            Relation::Equal => write!(f, "="),
+        }
+    }
+}
+
+/// A linear constraint of the form `expression relation rhs`.
+#[derive(Debug, Clone)]
+pub struct Constraint {
+    /// The left hand side of the constraint.
+    expression: LinearExpression,
+
+    /// The relation between the left and the right hand side.
+    relation: Relation,
+
+    /// The right hand side of the constraint.
+    rhs: f64,
+}
+
+impl Constraint {
+    /// Create a new Constraint.
+    ///
+    /// # Arguments
+    /// * `expression` - The left hand side of the constraint.
+    /// * `relation` - The relation between the left and the right hand side.
+    /// * `rhs` - The right hand side of the constraint.
+    ///
+    /// # Returns
+    /// A new Constraint.
+    pub fn new(expression: LinearExpression, relation: Relation, rhs: f64) -> Constraint {
+        Constraint {
+            expression,
+            relation,
+            rhs,
+        }
+    }
+
+    /// Get the left hand side of the constraint.
+    ///
+    /// # Returns
+    /// The left hand side of the constraint.
+    pub fn expression(&self) -> &LinearExpression {
+        &self.expression
+    }
+
+    /// Get the relation of the constraint.
+    ///
+    /// # Returns
+    /// The relation of the constraint.
+    pub fn relation(&self) -> Relation {
+        self.relation
+    }
+
+    /// Get the right hand side of the constraint.
+    ///
+    /// # Returns
+    /// The right hand side of the constraint.
+    pub fn rhs(&self) -> f64 {
+        self.rhs
+    }
+
+    /// Multiply the right hand side of the constraint by a factor.
+    ///
+    /// # Arguments
+    /// * `factor` - The factor.
+    ///
+    /// # Returns
+    /// A new Constraint with the scaled right hand side.
+    ///
+    /// # Note
+    /// The left hand side is left unchanged.
+    /// If the factor is negative, the relation is reversed.
+    pub fn multiply_rhs(&self, factor: f64) -> Constraint {
+        let relation = if factor < 0.0 {
+            self.relation.reversed()
+        } else {
+            self.relation
+        };
+        Constraint::new(self.expression.clone(), relation, self.rhs * factor)
+    }
+
+    /// Flip the constraint by multiplying both sides with -1.
+    ///
+    /// # Returns
+    /// A new Constraint with negated left and right hand sides and the reversed relation,
+    /// e.g. `ax <= b` becomes `-ax >= -b`.
+    pub fn flip(&self) -> Constraint {
+        Constraint::new(
+            self.expression.clone() * -1.0,
+            self.relation.reversed(),
+            -self.rhs,
+        )
+    }
+
+    /// Evaluate the left hand side of the constraint at the given assignment.
+    ///
+    /// # Arguments
+    /// * `assignment` - The values of the variables, keyed by variable name.
+    ///
+    /// # Returns
+    /// The value of the left hand side, without comparing it to the right hand side.
+    ///
+    /// # Note
+    /// Variables that are not part of the assignment are treated as zero.
+    pub fn lhs_value_at(&self, assignment: &HashMap<String, f64>) -> f64 {
+        self.expression.evaluate(assignment)
+    }
+
+    /// Check if the constraint is binding at the given assignment.
+    ///
+    /// # Arguments
+    /// * `assignment` - The values of the variables, keyed by variable name.
+    ///
+    /// # Returns
+    /// - `true` if the left hand side equals the right hand side within the tolerance.
+    /// - `false` otherwise.
+    ///
+    /// # Note
+    /// Variables that are not part of the assignment are treated as zero.
+    pub fn is_binding_at(&self, assignment: &HashMap<String, f64>) -> bool {
+        (self.lhs_value_at(assignment) - self.rhs).abs() <= EPSILON
+    }
+
+    /// Check if the constraint is satisfied by the given assignment.
+    ///
+    /// # Arguments
+    /// * `assignment` - The values of the variables, keyed by variable name.
+    ///
+    /// # Returns
+    /// - `true` if the relation between the left and the right hand side holds within the tolerance.
+    /// - `false` otherwise.
+    ///
+    /// # Note
+    /// Variables that are not part of the assignment are treated as zero.
+    pub fn satisfied_by(&self, assignment: &HashMap<String, f64>) -> bool {
+        let lhs = self.lhs_value_at(assignment);
+        match self.relation {
+            Relation::LessThanOrEqual => lhs <= self.rhs + EPSILON,
+            Relation::GreaterThanOrEqual => lhs >= self.rhs - EPSILON,
+            Relation::Equal => (lhs - self.rhs).abs() <= EPSILON,
+        }
+    }
+}
+
+/// Implement the Display trait for Constraint.
+impl Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.expression, self.relation, self.rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variable::Variable;
+
+    #[test]
+    fn multiply_rhs_scales_rhs() {
+        let x = Variable::new("x");
+        let constraint = Constraint::new((x.clone() * 3.0).into(), Relation::LessThanOrEqual, 6.0);
+
+        let doubled = constraint.multiply_rhs(2.0);
+        assert_eq!(doubled.relation(), Relation::LessThanOrEqual);
+        assert_eq!(doubled.rhs(), 12.0);
+        assert_eq!(doubled.expression().coefficient(&x), 3.0);
+
+        let negated = constraint.multiply_rhs(-1.0);
+        assert_eq!(negated.relation(), Relation::GreaterThanOrEqual);
+        assert_eq!(negated.rhs(), -6.0);
+        assert_eq!(negated.expression().coefficient(&x), 3.0);
+    }
+
+    #[test]
+    fn flip_negates_both_sides() {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let constraint = Constraint::new(
+            x.clone() * 2.0 + y.clone() * -1.0,
+            Relation::LessThanOrEqual,
+            4.0,
+        );
+
+        let flipped = constraint.flip();
+        assert_eq!(flipped.relation(), Relation::GreaterThanOrEqual);
+        assert_eq!(flipped.rhs(), -4.0);
+        assert_eq!(flipped.expression().coefficient(&x), -2.0);
+        assert_eq!(flipped.expression().coefficient(&y), 1.0);
+    }
+
+    #[test]
+    fn flip_twice_restores_constraint() {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let constraint = Constraint::new(
+            x.clone() * 2.0 + y.clone() * -1.0,
+            Relation::GreaterThanOrEqual,
+            4.0,
+        );
+
+        let restored = constraint.flip().flip();
+        assert_eq!(restored.relation(), constraint.relation());
+        assert_eq!(restored.rhs(), constraint.rhs());
+        assert_eq!(restored.expression().coefficient(&x), 2.0);
+        assert_eq!(restored.expression().coefficient(&y), -1.0);
+    }
+
+    #[test]
+    fn is_binding_at_textbook_optimum() {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let constraints = [
+            Constraint::new((x.clone() * 1.0).into(), Relation::LessThanOrEqual, 4.0),
+            Constraint::new((y.clone() * 2.0).into(), Relation::LessThanOrEqual, 12.0),
+            Constraint::new(x * 3.0 + y * 2.0, Relation::LessThanOrEqual, 18.0),
+        ];
+        let optimum = HashMap::from([("x".to_string(), 2.0), ("y".to_string(), 6.0)]);
+
+        let binding: Vec<bool> = constraints
+            .iter()
+            .map(|constraint| constraint.is_binding_at(&optimum))
+            .collect();
+        assert_eq!(binding, vec![false, true, true]);
+        // A vertex of a problem with two variables has at least two binding constraints.
+        assert!(binding.iter().filter(|&&is_binding| is_binding).count() >= 2);
+    }
+
+    #[test]
+    fn lhs_value_at_assignment() {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let constraint = Constraint::new(x * 1.0 + y * 1.0, Relation::LessThanOrEqual, 4.0);
+
+        let assignment = HashMap::from([("x".to_string(), 2.0), ("y".to_string(), 3.0)]);
+        assert_eq!(constraint.lhs_value_at(&assignment), 5.0);
+        assert!(!constraint.satisfied_by(&assignment));
+
+        let partial = HashMap::from([("x".to_string(), 2.0)]);
+        assert_eq!(constraint.lhs_value_at(&partial), 2.0);
+    }
+}
@@ -0,0 +1,559 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::{Add, Mul};
+
+use crate::variable::Variable;
+
+/// An error that occurs when the lengths of related slices do not match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimensionError {
+    /// The expected length.
+    pub expected: usize,
+
+    /// The actual length.
+    pub actual: usize,
+}
+
+/// A term of a linear expression, i.e. a variable multiplied by a coefficient.
+#[derive(Debug, Clone)]
+pub struct LinearTerm {
+    /// The variable of the term.
+    variable: Variable,
+
+    /// The coefficient of the term.
+    coefficient: f64,
+}
+
+impl LinearTerm {
+    /// Create a new LinearTerm.
+    ///
+    /// # Arguments
+    /// * `variable` - The variable of the term.
+    /// * `coefficient` - The coefficient of the term.
+    ///
+    /// # Returns
+    /// A new LinearTerm.
+    pub fn new(variable: Variable, coefficient: f64) -> LinearTerm {
+        LinearTerm {
+            variable,
+            coefficient,
+        }
+    }
+
+    /// Get the variable of the term.
+    ///
+    /// # Returns
+    /// The variable of the term.
+    pub fn variable(&self) -> &Variable {
+        &self.variable
+    }
+
+    /// Get the coefficient of the term.
+    ///
+    /// # Returns
+    /// The coefficient of the term.
+    pub fn coefficient(&self) -> f64 {
+        self.coefficient
+    }
+
+    /// Compare two terms by their coefficient only.
+    ///
+    /// # Arguments
+    /// * `other` - The term to compare with.
+    ///
+    /// # Returns
+    /// The ordering of the coefficients, which ignores the variables of the terms.
+    ///
+    /// # Note
+    /// The coefficients are compared using `f64::total_cmp`.
+    pub fn by_coefficient(&self, other: &LinearTerm) -> Ordering {
+        self.coefficient.total_cmp(&other.coefficient)
+    }
+}
+
+/// A linear expression, i.e. a sum of linear terms.
+#[derive(Debug, Clone, Default)]
+pub struct LinearExpression {
+    /// The terms of the expression.
+    terms: Vec<LinearTerm>,
+}
+
+impl LinearExpression {
+    /// Create a new LinearExpression from terms.
+    ///
+    /// # Arguments
+    /// * `terms` - The terms of the expression.
+    ///
+    /// # Returns
+    /// A new LinearExpression.
+    pub fn new(terms: Vec<LinearTerm>) -> LinearExpression {
+        LinearExpression { terms }
+    }
+
+    /// Create a new LinearExpression by zipping variables with coefficients.
+    ///
+    /// # Arguments
+    /// * `variables` - The variables of the terms.
+    /// * `coefficients` - The coefficients of the terms.
+    ///
+    /// # Returns
+    /// - `Ok(expression)` with one term per variable.
+    /// - `Err(DimensionError)` if the number of coefficients does not match the number of variables.
+    pub fn from_variable_slice(
+        variables: &[Variable],
+        coefficients: &[f64],
+    ) -> Result<LinearExpression, DimensionError> {
+        if variables.len() != coefficients.len() {
+            return Err(DimensionError {
+                expected: variables.len(),
+                actual: coefficients.len(),
+            });
+        }
+
+        let terms = variables
+            .iter()
+            .zip(coefficients)
+            .map(|(variable, &coefficient)| LinearTerm::new(variable.clone(), coefficient))
+            .collect();
+        Ok(LinearExpression::new(terms))
+    }
+
+    /// Create a new LinearExpression from a dense coefficient vector.
+    ///
+    /// # Arguments
+    /// * `coefficients` - The coefficient of each variable.
+    /// * `variables` - The variables in the order of the coefficients.
+    ///
+    /// # Returns
+    /// A new LinearExpression with one term per non-zero coefficient.
+    ///
+    /// # Note
+    /// Panics if the number of coefficients does not match the number of variables.
+    pub fn from_vector(coefficients: &[f64], variables: &[Variable]) -> LinearExpression {
+        // Check if the number of coefficients matches the number of variables.
+        if coefficients.len() != variables.len() {
+            panic!("The number of coefficients did not match the number of variables.");
+        }
+
+        let terms = variables
+            .iter()
+            .zip(coefficients)
+            .filter(|(_, &coefficient)| coefficient != 0.0)
+            .map(|(variable, &coefficient)| LinearTerm::new(variable.clone(), coefficient))
+            .collect();
+        LinearExpression::new(terms)
+    }
+
+    /// Convert the expression into a dense coefficient vector.
+    ///
+    /// # Arguments
+    /// * `variable_order` - The order of the variables in the vector.
+    ///
+    /// # Returns
+    /// The coefficient of each variable in the given order.
+    ///
+    /// # Note
+    /// Variables that do not occur in the expression have a coefficient of zero,
+    /// and terms of variables that are not part of the order are ignored.
+    pub fn to_vector(&self, variable_order: &[Variable]) -> Vec<f64> {
+        variable_order
+            .iter()
+            .map(|variable| self.coefficient(variable))
+            .collect()
+    }
+
+    /// Get the terms of the expression.
+    ///
+    /// # Returns
+    /// The terms of the expression.
+    pub fn terms(&self) -> &Vec<LinearTerm> {
+        &self.terms
+    }
+
+    /// Get the number of terms of the expression.
+    ///
+    /// # Returns
+    /// The number of terms of the expression.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Check if the expression has no terms.
+    ///
+    /// # Returns
+    /// - `true` if the expression has no terms.
+    /// - `false` if the expression has at least one term.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Add a term to the expression.
+    ///
+    /// # Arguments
+    /// * `term` - The term to add.
+    pub fn add_term(&mut self, term: LinearTerm) {
+        self.terms.push(term);
+    }
+
+    /// Add a term for a variable to the expression.
+    ///
+    /// # Arguments
+    /// * `var` - The variable of the term.
+    /// * `coefficient` - The coefficient of the term.
+    ///
+    /// # Note
+    /// The term is appended even if the variable already occurs in the expression.
+    pub fn add_term_for_variable(&mut self, var: &Variable, coefficient: f64) {
+        self.add_term(LinearTerm::new(var.clone(), coefficient));
+    }
+
+    /// Multiply all coefficients of the expression by a factor in place.
+    ///
+    /// # Arguments
+    /// * `factor` - The factor to multiply the coefficients by.
+    pub fn scale(&mut self, factor: f64) {
+        for term in &mut self.terms {
+            term.coefficient *= factor;
+        }
+    }
+
+    /// Multiply all coefficients of the expression by a factor.
+    ///
+    /// # Arguments
+    /// * `factor` - The factor to multiply the coefficients by.
+    ///
+    /// # Returns
+    /// The scaled expression.
+    pub fn scaled(mut self, factor: f64) -> LinearExpression {
+        self.scale(factor);
+        self
+    }
+
+    /// Get the coefficient of a variable in the expression.
+    ///
+    /// # Arguments
+    /// * `variable` - The variable.
+    ///
+    /// # Returns
+    /// The coefficient of the variable.
+    ///
+    /// # Note
+    /// If the variable occurs in multiple terms, the coefficients are summed up.
+    /// If the variable does not occur in the expression, the coefficient is zero.
+    pub fn coefficient(&self, variable: &Variable) -> f64 {
+        self.terms
+            .iter()
+            .filter(|term| term.variable == *variable)
+            .fold(0.0, |sum, term| sum + term.coefficient)
+    }
+
+    /// Get the variables of the expression.
+    ///
+    /// # Returns
+    /// The distinct variables of the expression in order of their first occurrence.
+    pub fn variables(&self) -> Vec<Variable> {
+        let mut variables: Vec<Variable> = Vec::new();
+        for term in &self.terms {
+            if !variables.contains(&term.variable) {
+                variables.push(term.variable.clone());
+            }
+        }
+        variables
+    }
+
+    /// Evaluate the expression at the given assignment.
+    ///
+    /// # Arguments
+    /// * `assignment` - The values of the variables, keyed by variable name.
+    ///
+    /// # Returns
+    /// The value of the expression.
+    ///
+    /// # Note
+    /// Variables that are not part of the assignment are treated as zero.
+    pub fn evaluate(&self, assignment: &HashMap<String, f64>) -> f64 {
+        self.terms
+            .iter()
+            .map(|term| term.coefficient * assignment.get(term.variable.name()).unwrap_or(&0.0))
+            .sum()
+    }
+
+    /// Get the coefficient with the largest absolute value.
+    ///
+    /// # Returns
+    /// - `Some(coefficient)` with the largest absolute value, including its sign.
+    /// - `None` if the expression is empty.
+    pub fn max_coefficient(&self) -> Option<f64> {
+        self.terms
+            .iter()
+            .map(|term| term.coefficient)
+            .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+    }
+
+    /// Get the non-zero coefficient with the smallest absolute value.
+    ///
+    /// # Returns
+    /// - `Some(coefficient)` with the smallest absolute value, including its sign.
+    /// - `None` if the expression has no non-zero coefficients.
+    pub fn min_coefficient(&self) -> Option<f64> {
+        self.terms
+            .iter()
+            .map(|term| term.coefficient)
+            .filter(|coefficient| *coefficient != 0.0)
+            .min_by(|a, b| a.abs().total_cmp(&b.abs()))
+    }
+}
+
+/// Multiply a variable by a coefficient to create a term.
+impl Mul<f64> for Variable {
+    type Output = LinearTerm;
+
+    fn mul(self, coefficient: f64) -> LinearTerm {
+        LinearTerm::new(self, coefficient)
+    }
+}
+
+/// Convert a single term into an expression.
+impl From<LinearTerm> for LinearExpression {
+    fn from(term: LinearTerm) -> LinearExpression {
+        LinearExpression::new(vec![term])
+    }
+}
+
+/// Add two terms to create an expression.
+impl Add<LinearTerm> for LinearTerm {
+    type Output = LinearExpression;
+
+    fn add(self, other: LinearTerm) -> LinearExpression {
+        LinearExpression::new(vec![self, other])
+    }
+}
+
+/// Add a term to an expression.
+impl Add<LinearTerm> for LinearExpression {
+    type Output = LinearExpression;
+
+    fn add(mut self, term: LinearTerm) -> LinearExpression {
+        self.add_term(term);
+        self
+    }
+}
+
+/// Add two expressions.
+impl Add<LinearExpression> for LinearExpression {
+    type Output = LinearExpression;
+
+    fn add(mut self, other: LinearExpression) -> LinearExpression {
+        self.terms.extend(other.terms);
+        self
+    }
+}
+
+/// Multiply all coefficients of an expression by a factor.
+impl Mul<f64> for LinearExpression {
+    type Output = LinearExpression;
+
+    fn mul(self, factor: f64) -> LinearExpression {
+        self.scaled(factor)
+    }
+}
+
+/// Compare terms by their coefficient and their variable.
+///
+/// # Note
+/// Two terms are equal if they have the same coefficient and the same variable.
+/// This keeps the comparison consistent with `Ord`.
+impl PartialEq for LinearTerm {
+    fn eq(&self, other: &LinearTerm) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for LinearTerm {}
+
+/// Order terms by their coefficient.
+impl PartialOrd for LinearTerm {
+    fn partial_cmp(&self, other: &LinearTerm) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Order terms by their coefficient.
+///
+/// # Note
+/// The coefficients are compared using `f64::total_cmp`, which gives a total order
+/// even in the presence of `NaN`. Terms with equal coefficients are ordered by the
+/// name of their variable. Use `LinearTerm::by_coefficient` to ignore the variables.
+impl Ord for LinearTerm {
+    fn cmp(&self, other: &LinearTerm) -> Ordering {
+        self.by_coefficient(other)
+            .then_with(|| self.variable.name().cmp(other.variable.name()))
+    }
+}
+
+/// Implement the Display trait for LinearTerm.
+impl Display for LinearTerm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.coefficient, self.variable)
+    }
+}
+
+/// Implement the Display trait for LinearExpression.
+///
+/// # Note
+/// An empty expression is displayed as `0`.
+impl Display for LinearExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+
+        for (i, term) in self.terms.iter().enumerate() {
+            if i == 0 {
+                write!(f, "{}", term)?;
+            } else if term.coefficient < 0.0 {
+                write!(f, " - {}{}", -term.coefficient, term.variable)?;
+            } else {
+                write!(f, " + {}", term)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Implement the Display trait for DimensionError.
+impl Display for DimensionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Expected {} elements, but got {}.",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for DimensionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coefficient_of_absent_variable_is_positive_zero() {
+        let expression = Variable::new("x") * 2.0 + Variable::new("x") * 1.5;
+        assert_eq!(expression.coefficient(&Variable::new("x")), 3.5);
+
+        let absent = expression.coefficient(&Variable::new("y"));
+        assert_eq!(absent, 0.0);
+        assert!(absent.is_sign_positive());
+    }
+
+    #[test]
+    fn from_variable_slice_zips_variables_and_coefficients() {
+        let variables = Variable::family("x", 3);
+        let expression =
+            LinearExpression::from_variable_slice(&variables, &[1.0, -2.0, 3.5]).unwrap();
+        assert_eq!(expression.len(), 3);
+        assert_eq!(expression.coefficient(&variables[0]), 1.0);
+        assert_eq!(expression.coefficient(&variables[1]), -2.0);
+        assert_eq!(expression.coefficient(&variables[2]), 3.5);
+    }
+
+    #[test]
+    fn from_variable_slice_rejects_mismatched_lengths() {
+        let variables = Variable::family("x", 3);
+        let result = LinearExpression::from_variable_slice(&variables, &[1.0, 2.0]);
+        assert_eq!(
+            result.err(),
+            Some(DimensionError {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn max_and_min_coefficient() {
+        let variables = Variable::family("x", 3);
+        let expression =
+            LinearExpression::from_variable_slice(&variables, &[-5.0, 2.0, 0.5]).unwrap();
+        assert_eq!(expression.max_coefficient(), Some(-5.0));
+        assert_eq!(expression.min_coefficient(), Some(0.5));
+
+        let empty = LinearExpression::default();
+        assert_eq!(empty.max_coefficient(), None);
+        assert_eq!(empty.min_coefficient(), None);
+    }
+
+    #[test]
+    fn max_and_min_of_terms() {
+        let terms = [
+            Variable::new("x") * 3.0,
+            Variable::new("y") * -1.0,
+            Variable::new("z") * 5.0,
+        ];
+        assert_eq!(terms.iter().max().unwrap().variable().name(), "z");
+        assert_eq!(terms.iter().min().unwrap().variable().name(), "y");
+    }
+
+    #[test]
+    fn terms_with_different_variables_are_not_equal() {
+        let x = Variable::new("x") * 3.0;
+        let y = Variable::new("y") * 3.0;
+        assert!(x != y);
+        assert!(x == Variable::new("x") * 3.0);
+        assert_eq!(x.by_coefficient(&y), Ordering::Equal);
+        assert_eq!(x.cmp(&y), Ordering::Less);
+    }
+
+    #[test]
+    fn scale_by_zero_and_minus_one() {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let expression = x.clone() * 2.0 + y.clone() * -3.0;
+
+        let mut zero = expression.clone();
+        zero.scale(0.0);
+        assert_eq!(zero.terms().len(), 2);
+        assert_eq!(zero.coefficient(&x), 0.0);
+        assert_eq!(zero.coefficient(&y), 0.0);
+
+        let negated = expression.scaled(-1.0);
+        assert_eq!(negated.coefficient(&x), -2.0);
+        assert_eq!(negated.coefficient(&y), 3.0);
+    }
+
+    #[test]
+    fn to_vector_and_from_vector_round_trip() {
+        let variables = Variable::family("x", 3);
+        let expression = variables[2].clone() * 4.0 + variables[0].clone() * -1.0;
+
+        let coefficients = expression.to_vector(&variables);
+        assert_eq!(coefficients, vec![-1.0, 0.0, 4.0]);
+
+        let restored = LinearExpression::from_vector(&coefficients, &variables);
+        assert_eq!(restored.terms().len(), 2);
+        assert_eq!(restored.to_vector(&variables), coefficients);
+    }
+
+    #[test]
+    fn add_term_for_variable_appends_term() {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let mut expression: LinearExpression = (x.clone() * 2.0).into();
+
+        expression.add_term_for_variable(&y, 3.0);
+        assert_eq!(expression.terms().len(), 2);
+        assert_eq!(expression.coefficient(&y), 3.0);
+
+        // A repeated variable is appended as a separate term, and the coefficients are summed up.
+        expression.add_term_for_variable(&x, 1.0);
+        assert_eq!(expression.terms().len(), 3);
+        assert_eq!(expression.coefficient(&x), 3.0);
+    }
+}
@@ -0,0 +1,387 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::constraint::{Constraint, Relation};
+use crate::expression::{LinearExpression, LinearTerm};
+use crate::problem::{Objective, ObjectiveType, Problem};
+use crate::variable::Variable;
+
+/// An error that can occur when reading an LP file.
+#[derive(Debug)]
+pub enum LpReadError {
+    /// The file could not be read.
+    Io(std::io::Error),
+}
+
+/// An error that can occur when parsing an LP file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LpParseError {
+    /// The file is not valid UTF-8.
+    InvalidUtf8,
+
+    /// The file does not start with a `Maximize` or `Minimize` section.
+    MissingObjective,
+
+    /// The section in the given line is not supported.
+    UnsupportedSection {
+        /// The line number, starting at one.
+        line: usize,
+    },
+
+    /// The expression in the given line could not be parsed.
+    InvalidExpression {
+        /// The line number, starting at one.
+        line: usize,
+    },
+
+    /// The constraint in the given line could not be parsed.
+    InvalidConstraint {
+        /// The line number, starting at one.
+        line: usize,
+    },
+}
+
+/// The section of an LP file that is currently parsed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section {
+    /// Before the objective section.
+    Start,
+
+    /// The objective section.
+    Objective,
+
+    /// The constraint section.
+    Constraints,
+
+    /// After the end marker.
+    End,
+}
+
+/// A file in the LP file format.
+///
+/// # Note
+/// The file is read into memory when it is opened, but it is only parsed on demand.
+/// The supported subset of the format consists of an objective section,
+/// a `Subject To` section with one constraint per line and the `End` marker.
+/// Lines starting with `\` are comments.
+pub struct LpFile {
+    /// The raw contents of the file.
+    bytes: Vec<u8>,
+}
+
+impl LpFile {
+    /// Open an LP file.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the file.
+    ///
+    /// # Returns
+    /// - `Ok(file)` if the file could be read.
+    /// - `Err(LpReadError::Io)` otherwise.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<LpFile, LpReadError> {
+        let bytes = std::fs::read(path).map_err(LpReadError::Io)?;
+        Ok(LpFile { bytes })
+    }
+
+    /// Create an LP file from its raw contents.
+    ///
+    /// # Arguments
+    /// * `bytes` - The raw contents of the file.
+    ///
+    /// # Returns
+    /// A new LpFile.
+    pub fn from_bytes(bytes: Vec<u8>) -> LpFile {
+        LpFile { bytes }
+    }
+
+    /// Get the raw contents of the file.
+    ///
+    /// # Returns
+    /// The raw contents of the file.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Parse the problem of the file.
+    ///
+    /// # Returns
+    /// - `Ok(problem)` if the file could be parsed.
+    /// - `Err(LpParseError)` otherwise.
+    pub fn parse_problem(&self) -> Result<Problem, LpParseError> {
+        let text = std::str::from_utf8(&self.bytes).map_err(|_| LpParseError::InvalidUtf8)?;
+
+        let mut section = Section::Start;
+        let mut objective_type = None;
+        let mut objective_expression = LinearExpression::default();
+        let mut constraints = Vec::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('\\') {
+                continue;
+            }
+
+            // Check for section headers.
+            match line.to_lowercase().as_str() {
+                "maximize" | "maximise" | "max" => {
+                    section = Section::Objective;
+                    objective_type = Some(ObjectiveType::Maximize);
+                    continue;
+                }
+                "minimize" | "minimise" | "min" => {
+                    section = Section::Objective;
+                    objective_type = Some(ObjectiveType::Minimize);
+                    continue;
+                }
+                "subject to" | "such that" | "st" | "s.t." => {
+                    if section == Section::Start {
+                        return Err(LpParseError::MissingObjective);
+                    }
+                    section = Section::Constraints;
+                    continue;
+                }
+                "end" => {
+                    section = Section::End;
+                    continue;
+                }
+                "bounds" | "general" | "generals" | "binary" | "binaries" => {
+                    return Err(LpParseError::UnsupportedSection { line: line_number });
+                }
+                _ => {}
+            }
+
+            let body = LpFile::strip_label(line);
+            match section {
+                Section::Start => return Err(LpParseError::MissingObjective),
+                Section::Objective => {
+                    // The objective may span multiple lines.
+                    let expression = LpFile::parse_expression(body)
+                        .ok_or(LpParseError::InvalidExpression { line: line_number })?;
+                    objective_expression = objective_expression + expression;
+                }
+                Section::Constraints => {
+                    let constraint = LpFile::parse_constraint(body)
+                        .ok_or(LpParseError::InvalidConstraint { line: line_number })?;
+                    constraints.push(constraint);
+                }
+                Section::End => break,
+            }
+        }
+
+        let objective_type = objective_type.ok_or(LpParseError::MissingObjective)?;
+        let objective = Objective::new(objective_type, objective_expression);
+        Ok(Problem::new(objective, constraints))
+    }
+
+    /// Remove the optional `name:` label from a line.
+    ///
+    /// # Arguments
+    /// * `line` - The line.
+    ///
+    /// # Returns
+    /// The line without the label.
+    fn strip_label(line: &str) -> &str {
+        match line.find(':') {
+            Some(position) => &line[position + 1..],
+            None => line,
+        }
+    }
+
+    /// Parse a constraint of the form `expression relation rhs`.
+    ///
+    /// # Arguments
+    /// * `text` - The text of the constraint.
+    ///
+    /// # Returns
+    /// The constraint, or `None` if the text is not a valid constraint.
+    fn parse_constraint(text: &str) -> Option<Constraint> {
+        // The two character relations have to be checked first.
+        let relations = [
+            ("<=", Relation::LessThanOrEqual),
+            (">=", Relation::GreaterThanOrEqual),
+            ("=<", Relation::LessThanOrEqual),
+            ("=>", Relation::GreaterThanOrEqual),
+            ("<", Relation::LessThanOrEqual),
+            (">", Relation::GreaterThanOrEqual),
+            ("=", Relation::Equal),
+        ];
+
+        let (position, symbol, relation) = relations
+            .iter()
+            .find_map(|&(symbol, relation)| text.find(symbol).map(|p| (p, symbol, relation)))?;
+
+        let expression = LpFile::parse_expression(&text[..position])?;
+        let rhs = text[position + symbol.len()..].trim().parse::<f64>().ok()?;
+        Some(Constraint::new(expression, relation, rhs))
+    }
+
+    /// Parse an expression of the form `3 x + 2 y - z`.
+    ///
+    /// # Arguments
+    /// * `text` - The text of the expression.
+    ///
+    /// # Returns
+    /// The expression, or `None` if the text is not a valid expression.
+    fn parse_expression(text: &str) -> Option<LinearExpression> {
+        let mut expression = LinearExpression::default();
+        let mut sign = 1.0;
+        let mut coefficient = None;
+
+        for token in text.split_whitespace() {
+            match token {
+                "+" => {}
+                "-" => sign = -sign,
+                _ => {
+                    if let Ok(value) = token.parse::<f64>() {
+                        // Two consecutive numbers are not a valid expression.
+                        if coefficient.is_some() {
+                            return None;
+                        }
+                        coefficient = Some(value);
+                    } else {
+                        let value = sign * coefficient.take().unwrap_or(1.0);
+                        expression.add_term(LinearTerm::new(Variable::new(token), value));
+                        sign = 1.0;
+                    }
+                }
+            }
+        }
+
+        // A dangling sign or coefficient is not a valid expression.
+        if coefficient.is_some() || sign != 1.0 {
+            return None;
+        }
+        Some(expression)
+    }
+}
+
+/// Implement the Display trait for LpReadError.
+impl Display for LpReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LpReadError::Io(error) => write!(f, "The LP file could not be read: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for LpReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LpReadError::Io(error) => Some(error),
+        }
+    }
+}
+
+/// Implement the Display trait for LpParseError.
+impl Display for LpParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LpParseError::InvalidUtf8 => write!(f, "The LP file is not valid UTF-8."),
+            LpParseError::MissingObjective => {
+                write!(f, "The LP file does not start with an objective section.")
+            }
+            LpParseError::UnsupportedSection { line } => {
+                write!(f, "The section in line {} is not supported.", line)
+            }
+            LpParseError::InvalidExpression { line } => {
+                write!(f, "The expression in line {} is invalid.", line)
+            }
+            LpParseError::InvalidConstraint { line } => {
+                write!(f, "The constraint in line {} is invalid.", line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LpParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_problem() -> Problem {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        Problem::new(
+            Objective::minimize(x.clone() * 2.0 + y.clone() * -3.5),
+            vec![
+                Constraint::new(
+                    x.clone() * 1.0 + y.clone() * 1.0,
+                    Relation::LessThanOrEqual,
+                    4.0,
+                ),
+                Constraint::new(
+                    x.clone() * 3.0 + y.clone() * -1.0,
+                    Relation::GreaterThanOrEqual,
+                    -2.0,
+                ),
+                Constraint::new((y * 1.0).into(), Relation::Equal, 1.5),
+            ],
+        )
+    }
+
+    fn assert_same_problem(a: &Problem, b: &Problem) {
+        assert_eq!(
+            a.objective().objective_type(),
+            b.objective().objective_type()
+        );
+        assert_eq!(a.variables(), b.variables());
+        for variable in a.variables() {
+            assert_eq!(
+                a.objective().expression().coefficient(variable),
+                b.objective().expression().coefficient(variable)
+            );
+        }
+
+        assert_eq!(a.constraints().len(), b.constraints().len());
+        for (c, d) in a.constraints().iter().zip(b.constraints()) {
+            assert_eq!(c.relation(), d.relation());
+            assert_eq!(c.rhs(), d.rhs());
+            for variable in a.variables() {
+                assert_eq!(
+                    c.expression().coefficient(variable),
+                    d.expression().coefficient(variable)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lp_file_round_trip() {
+        let problem = example_problem();
+        let path =
+            std::env::temp_dir().join(format!("verysimplex-round-trip-{}.lp", std::process::id()));
+        problem.to_lp_file(&path).unwrap();
+
+        let file = LpFile::open(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_same_problem(&file.parse_problem().unwrap(), &problem);
+    }
+
+    #[test]
+    fn lp_string_round_trip() {
+        let problem = example_problem();
+        let file = LpFile::from_bytes(problem.to_lp_string().into_bytes());
+        assert_same_problem(&file.parse_problem().unwrap(), &problem);
+    }
+
+    #[test]
+    fn parse_problem_errors() {
+        let file = LpFile::from_bytes(b"Subject To\n c0: x <= 1\nEnd\n".to_vec());
+        assert_eq!(
+            file.parse_problem().err(),
+            Some(LpParseError::MissingObjective)
+        );
+
+        let file = LpFile::from_bytes(b"Maximize\n obj: x\nBounds\nEnd\n".to_vec());
+        assert_eq!(
+            file.parse_problem().err(),
+            Some(LpParseError::UnsupportedSection { line: 3 })
+        );
+    }
+}
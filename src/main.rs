@@ -1,6 +1,16 @@
 // Copyright 2024 Felix Kahle. All rights reserved.
 
+mod constraint;
+mod expression;
+mod io;
+mod matrix;
+mod problem;
+mod random;
+mod solution;
+mod solver;
 mod tableau;
+mod tableau_builder;
+mod variable;
 
 fn main() {
     println!("Hello, world!");
@@ -0,0 +1,854 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use nalgebra::DMatrix;
+
+/// The tolerance below which an element is considered to be zero.
+const EPSILON: f64 = 1e-9;
+
+/// An error that can occur when operating on a matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Matrix2DError {
+    /// The dimensions of the matrices do not match.
+    DimensionMismatch {
+        /// The expected dimension.
+        expected: usize,
+
+        /// The actual dimension.
+        actual: usize,
+    },
+
+    /// The index of a row is out of bounds.
+    IndexOutOfBounds(usize),
+}
+
+/// A dense two dimensional matrix.
+///
+/// # Note
+/// The elements are stored in row-major order in a flat vector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix2D<T> {
+    /// The elements of the matrix in row-major order.
+    data: Vec<T>,
+
+    /// The number of rows of the matrix.
+    rows: usize,
+
+    /// The number of columns of the matrix.
+    columns: usize,
+}
+
+impl<T> Matrix2D<T> {
+    /// Create a new Matrix2D from its elements.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of rows of the matrix.
+    /// * `columns` - The number of columns of the matrix.
+    /// * `data` - The elements of the matrix in row-major order.
+    ///
+    /// # Returns
+    /// A new Matrix2D.
+    pub fn new(rows: usize, columns: usize, data: Vec<T>) -> Matrix2D<T> {
+        // Check if the number of elements matches the dimensions of the matrix.
+        if data.len() != rows * columns {
+            panic!("The number of elements did not match the dimensions of the matrix.");
+        }
+
+        Matrix2D {
+            data,
+            rows,
+            columns,
+        }
+    }
+
+    /// Create a new Matrix2D from its rows.
+    ///
+    /// # Arguments
+    /// * `rows` - The rows of the matrix.
+    ///
+    /// # Returns
+    /// - `Some(matrix)` if all rows have the same length.
+    /// - `None` otherwise.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Matrix2D<T>> {
+        let num_rows = rows.len();
+        let num_columns = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != num_columns) {
+            return None;
+        }
+
+        let data = rows.into_iter().flatten().collect();
+        Some(Matrix2D::new(num_rows, num_columns, data))
+    }
+
+    /// Create a new Matrix2D from its columns.
+    ///
+    /// # Arguments
+    /// * `columns` - The columns of the matrix.
+    ///
+    /// # Returns
+    /// - `Some(matrix)` if all columns have the same length.
+    /// - `None` otherwise.
+    pub fn from_columns(columns: Vec<Vec<T>>) -> Option<Matrix2D<T>> {
+        let num_columns = columns.len();
+        let num_rows = columns.first().map_or(0, |column| column.len());
+        if columns.iter().any(|column| column.len() != num_rows) {
+            return None;
+        }
+
+        // Take one element of every column in turn to get the row-major order.
+        let mut columns: Vec<_> = columns
+            .into_iter()
+            .map(|column| column.into_iter())
+            .collect();
+        let mut data = Vec::with_capacity(num_rows * num_columns);
+        for _ in 0..num_rows {
+            data.extend(columns.iter_mut().filter_map(|column| column.next()));
+        }
+        Some(Matrix2D::new(num_rows, num_columns, data))
+    }
+
+    /// Get the number of rows of the matrix.
+    ///
+    /// # Returns
+    /// The number of rows of the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the number of columns of the matrix.
+    ///
+    /// # Returns
+    /// The number of columns of the matrix.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Get the elements of the matrix.
+    ///
+    /// # Returns
+    /// The elements of the matrix in row-major order.
+    pub fn data(&self) -> &Vec<T> {
+        &self.data
+    }
+
+    /// Get a reference to an element of the matrix.
+    ///
+    /// # Arguments
+    /// * `row` - The index of the row.
+    /// * `column` - The index of the column.
+    ///
+    /// # Returns
+    /// - `Some(element)` if the indices are in bounds.
+    /// - `None` otherwise.
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row >= self.rows || column >= self.columns {
+            return None;
+        }
+        self.data.get(row * self.columns + column)
+    }
+
+    /// Get a mutable reference to an element of the matrix.
+    ///
+    /// # Arguments
+    /// * `row` - The index of the row.
+    /// * `column` - The index of the column.
+    ///
+    /// # Returns
+    /// - `Some(element)` if the indices are in bounds.
+    /// - `None` otherwise.
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        if row >= self.rows || column >= self.columns {
+            return None;
+        }
+        self.data.get_mut(row * self.columns + column)
+    }
+}
+
+impl<T> Matrix2D<T> {
+    /// Append the rows of another matrix to the bottom of the matrix.
+    ///
+    /// # Arguments
+    /// * `additional` - The matrix whose rows are appended.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the rows were appended.
+    /// - `Err(Matrix2DError::DimensionMismatch)` if the number of columns does not match.
+    pub fn extend_rows(&mut self, additional: Matrix2D<T>) -> Result<(), Matrix2DError> {
+        if additional.columns != self.columns {
+            return Err(Matrix2DError::DimensionMismatch {
+                expected: self.columns,
+                actual: additional.columns,
+            });
+        }
+
+        // In row-major order the new rows are simply appended to the data.
+        self.rows += additional.rows;
+        self.data.extend(additional.data);
+        Ok(())
+    }
+
+    /// Append the columns of another matrix to the right of the matrix.
+    ///
+    /// # Arguments
+    /// * `additional` - The matrix whose columns are appended.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the columns were appended.
+    /// - `Err(Matrix2DError::DimensionMismatch)` if the number of rows does not match.
+    pub fn extend_columns(&mut self, additional: Matrix2D<T>) -> Result<(), Matrix2DError> {
+        if additional.rows != self.rows {
+            return Err(Matrix2DError::DimensionMismatch {
+                expected: self.rows,
+                actual: additional.rows,
+            });
+        }
+
+        // In row-major order the rows have to be interleaved.
+        let columns = self.columns + additional.columns;
+        let mut data = Vec::with_capacity(self.rows * columns);
+        let mut own = self.data.drain(..);
+        let mut other = additional.data.into_iter();
+        for _ in 0..self.rows {
+            data.extend(own.by_ref().take(self.columns));
+            data.extend(other.by_ref().take(additional.columns));
+        }
+        drop(own);
+
+        self.data = data;
+        self.columns = columns;
+        Ok(())
+    }
+}
+
+impl<T: Clone> Matrix2D<T> {
+    /// Create a new Matrix2D with all elements set to the same value.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of rows of the matrix.
+    /// * `columns` - The number of columns of the matrix.
+    /// * `value` - The value of all elements.
+    ///
+    /// # Returns
+    /// A new Matrix2D.
+    pub fn filled(rows: usize, columns: usize, value: T) -> Matrix2D<T> {
+        Matrix2D::new(rows, columns, vec![value; rows * columns])
+    }
+}
+
+impl Matrix2D<f64> {
+    /// Create a new Matrix2D with all elements set to zero.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of rows of the matrix.
+    /// * `columns` - The number of columns of the matrix.
+    ///
+    /// # Returns
+    /// A new Matrix2D.
+    pub fn zeros(rows: usize, columns: usize) -> Matrix2D<f64> {
+        Matrix2D::filled(rows, columns, 0.0)
+    }
+
+    /// Create a new Matrix2D with all elements set to one.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of rows of the matrix.
+    /// * `columns` - The number of columns of the matrix.
+    ///
+    /// # Returns
+    /// A new Matrix2D.
+    pub fn ones(rows: usize, columns: usize) -> Matrix2D<f64> {
+        Matrix2D::filled(rows, columns, 1.0)
+    }
+
+    /// Create a new square identity matrix.
+    ///
+    /// # Arguments
+    /// * `n` - The number of rows and columns of the matrix.
+    ///
+    /// # Returns
+    /// A new Matrix2D with ones on the diagonal and zeros elsewhere.
+    pub fn identity(n: usize) -> Matrix2D<f64> {
+        let mut matrix = Matrix2D::zeros(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = 1.0;
+        }
+        matrix
+    }
+
+    /// Create a new Matrix2D from a nalgebra matrix.
+    ///
+    /// # Arguments
+    /// * `matrix` - The nalgebra matrix.
+    ///
+    /// # Returns
+    /// A new Matrix2D with the same elements.
+    ///
+    /// # Note
+    /// nalgebra stores its elements in column-major order,
+    /// so the elements have to be copied in transposed order.
+    pub fn from_dmatrix(matrix: &DMatrix<f64>) -> Matrix2D<f64> {
+        let data = matrix.transpose().as_slice().to_vec();
+        Matrix2D::new(matrix.nrows(), matrix.ncols(), data)
+    }
+
+    /// Convert the matrix into a nalgebra matrix.
+    ///
+    /// # Returns
+    /// A nalgebra matrix with the same elements.
+    ///
+    /// # Note
+    /// nalgebra stores its elements in column-major order,
+    /// so the elements have to be copied in transposed order.
+    pub fn to_dmatrix(&self) -> DMatrix<f64> {
+        DMatrix::from_row_slice(self.rows, self.columns, &self.data)
+    }
+
+    /// Get the maximum element of the matrix.
+    ///
+    /// # Returns
+    /// The maximum element, or negative infinity if the matrix is empty.
+    pub fn max(&self) -> f64 {
+        self.data.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    /// Get the minimum element of the matrix.
+    ///
+    /// # Returns
+    /// The minimum element, or positive infinity if the matrix is empty.
+    pub fn min(&self) -> f64 {
+        self.data.iter().copied().fold(f64::INFINITY, f64::min)
+    }
+
+    /// Get the maximum element of a row.
+    ///
+    /// # Arguments
+    /// * `row` - The index of the row.
+    ///
+    /// # Returns
+    /// - `Some(max)` if the row exists and is not empty.
+    /// - `None` otherwise.
+    pub fn row_max(&self, row: usize) -> Option<f64> {
+        if row >= self.rows || self.columns == 0 {
+            return None;
+        }
+
+        let start = row * self.columns;
+        let values = &self.data[start..start + self.columns];
+        Some(values.iter().copied().fold(f64::NEG_INFINITY, f64::max))
+    }
+
+    /// Get the maximum element of a column.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    ///
+    /// # Returns
+    /// - `Some(max)` if the column exists and is not empty.
+    /// - `None` otherwise.
+    pub fn col_max(&self, col: usize) -> Option<f64> {
+        if col >= self.columns || self.rows == 0 {
+            return None;
+        }
+
+        let values = self.data.iter().skip(col).step_by(self.columns);
+        Some(values.copied().fold(f64::NEG_INFINITY, f64::max))
+    }
+
+    /// Get the position of the element that is preferred by a comparison.
+    ///
+    /// # Arguments
+    /// * `values` - The values to search.
+    /// * `better` - Returns `true` if the first value is preferred over the second one.
+    ///
+    /// # Returns
+    /// The position of the first preferred value, or `None` if there are no values.
+    fn position_by<'a>(
+        values: impl Iterator<Item = &'a f64>,
+        better: impl Fn(f64, f64) -> bool,
+    ) -> Option<usize> {
+        let mut best: Option<(usize, f64)> = None;
+        for (i, &value) in values.enumerate() {
+            if best.is_none_or(|(_, best_value)| better(value, best_value)) {
+                best = Some((i, value));
+            }
+        }
+        best.map(|(i, _)| i)
+    }
+
+    /// Get the position of the maximum element of the matrix.
+    ///
+    /// # Returns
+    /// - `Some((row, column))` of the first maximum element.
+    /// - `None` if the matrix is empty.
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        Matrix2D::position_by(self.data.iter(), |a, b| a > b)
+            .map(|i| (i / self.columns, i % self.columns))
+    }
+
+    /// Get the position of the minimum element of the matrix.
+    ///
+    /// # Returns
+    /// - `Some((row, column))` of the first minimum element.
+    /// - `None` if the matrix is empty.
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        Matrix2D::position_by(self.data.iter(), |a, b| a < b)
+            .map(|i| (i / self.columns, i % self.columns))
+    }
+
+    /// Get the row of the maximum element of a column.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    ///
+    /// # Returns
+    /// - `Some(row)` of the first maximum element of the column.
+    /// - `None` if the column does not exist or is empty.
+    pub fn argmax_col(&self, col: usize) -> Option<usize> {
+        if col >= self.columns {
+            return None;
+        }
+        Matrix2D::position_by(self.data.iter().skip(col).step_by(self.columns), |a, b| {
+            a > b
+        })
+    }
+
+    /// Get the row of the minimum element of a column.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    ///
+    /// # Returns
+    /// - `Some(row)` of the first minimum element of the column.
+    /// - `None` if the column does not exist or is empty.
+    pub fn argmin_col(&self, col: usize) -> Option<usize> {
+        if col >= self.columns {
+            return None;
+        }
+        Matrix2D::position_by(self.data.iter().skip(col).step_by(self.columns), |a, b| {
+            a < b
+        })
+    }
+
+    /// Transform the matrix into reduced row echelon form.
+    ///
+    /// # Returns
+    /// The rank of the matrix, i.e. the number of pivot rows.
+    ///
+    /// # Note
+    /// The elimination is performed in place using partial pivoting,
+    /// i.e. the row with the largest absolute value in the pivot column is chosen as the pivot row.
+    /// Elements with an absolute value below the tolerance are treated as zero.
+    pub fn gaussian_elimination(&mut self) -> usize {
+        let mut rank = 0;
+
+        for col in 0..self.columns {
+            if rank == self.rows {
+                break;
+            }
+
+            // Find the row with the largest absolute value in the current column.
+            let mut pivot_row = rank;
+            for r in rank + 1..self.rows {
+                if self[(r, col)].abs() > self[(pivot_row, col)].abs() {
+                    pivot_row = r;
+                }
+            }
+
+            // Skip the column if it has no pivot.
+            if self[(pivot_row, col)].abs() <= EPSILON {
+                continue;
+            }
+
+            // Move the pivot row into position.
+            if pivot_row != rank {
+                for c in 0..self.columns {
+                    self.data
+                        .swap(pivot_row * self.columns + c, rank * self.columns + c);
+                }
+            }
+
+            // Scale the pivot row so that the pivot element becomes 1.
+            let pivot_element = self[(rank, col)];
+            for c in 0..self.columns {
+                self[(rank, c)] /= pivot_element;
+            }
+
+            // Eliminate all other entries in the pivot column.
+            for r in 0..self.rows {
+                if r != rank {
+                    let factor = self[(r, col)];
+                    for c in 0..self.columns {
+                        let value = self[(rank, c)];
+                        self[(r, c)] -= factor * value;
+                    }
+                }
+            }
+
+            rank += 1;
+        }
+
+        rank
+    }
+
+    /// Get the rank of the matrix.
+    ///
+    /// # Returns
+    /// The number of linearly independent rows of the matrix.
+    ///
+    /// # Note
+    /// The rank is computed by Gaussian elimination on a copy of the matrix,
+    /// so the matrix itself is left unchanged.
+    pub fn rank(&self) -> usize {
+        self.clone().gaussian_elimination()
+    }
+
+    /// Subtract a multiple of one row from another row.
+    ///
+    /// # Arguments
+    /// * `target` - The index of the row that is modified.
+    /// * `source` - The index of the row that is subtracted.
+    /// * `factor` - The factor the source row is multiplied by.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the target row was updated to `row[target] - factor * row[source]`.
+    /// - `Err(Matrix2DError::IndexOutOfBounds)` if one of the rows is out of bounds.
+    pub fn apply_row_operation(
+        &mut self,
+        target: usize,
+        source: usize,
+        factor: f64,
+    ) -> Result<(), Matrix2DError> {
+        for row in [target, source] {
+            if row >= self.rows {
+                return Err(Matrix2DError::IndexOutOfBounds(row));
+            }
+        }
+
+        for c in 0..self.columns {
+            let value = self[(source, c)];
+            self[(target, c)] -= factor * value;
+        }
+        Ok(())
+    }
+
+    /// Multiply the matrix by a vector.
+    ///
+    /// # Arguments
+    /// * `vec` - The vector, with one entry per column.
+    ///
+    /// # Returns
+    /// - `Ok(product)` with the product `A * v`, with one entry per row.
+    /// - `Err(Matrix2DError::DimensionMismatch)` if the length of the vector does not match
+    ///   the number of columns.
+    pub fn mul_vector(&self, vec: &[f64]) -> Result<Vec<f64>, Matrix2DError> {
+        if vec.len() != self.columns {
+            return Err(Matrix2DError::DimensionMismatch {
+                expected: self.columns,
+                actual: vec.len(),
+            });
+        }
+
+        let product = (0..self.rows)
+            .map(|r| (0..self.columns).map(|c| self[(r, c)] * vec[c]).sum())
+            .collect();
+        Ok(product)
+    }
+
+    /// Multiply the transpose of the matrix by a vector.
+    ///
+    /// # Arguments
+    /// * `vec` - The vector, with one entry per row.
+    ///
+    /// # Returns
+    /// - `Ok(product)` with the product `A^T * v`, with one entry per column.
+    /// - `Err(Matrix2DError::DimensionMismatch)` if the length of the vector does not match
+    ///   the number of rows.
+    ///
+    /// # Note
+    /// The transpose is never built explicitly.
+    pub fn transpose_mul_vector(&self, vec: &[f64]) -> Result<Vec<f64>, Matrix2DError> {
+        if vec.len() != self.rows {
+            return Err(Matrix2DError::DimensionMismatch {
+                expected: self.rows,
+                actual: vec.len(),
+            });
+        }
+
+        let product = (0..self.columns)
+            .map(|c| (0..self.rows).map(|r| self[(r, c)] * vec[r]).sum())
+            .collect();
+        Ok(product)
+    }
+}
+
+/// Index the matrix by a `(row, column)` pair.
+///
+/// # Note
+/// Panics if the indices are out of bounds.
+impl<T> Index<(usize, usize)> for Matrix2D<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        self.get(row, column)
+            .expect("The index was out of the bounds of the matrix.")
+    }
+}
+
+/// Mutably index the matrix by a `(row, column)` pair.
+///
+/// # Note
+/// Panics if the indices are out of bounds.
+impl<T> IndexMut<(usize, usize)> for Matrix2D<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        self.get_mut(row, column)
+            .expect("The index was out of the bounds of the matrix.")
+    }
+}
+
+/// Implement the Display trait for Matrix2DError.
+impl Display for Matrix2DError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Matrix2DError::DimensionMismatch { expected, actual } => write!(
+                f,
+                "Expected a dimension of {}, but got {}.",
+                expected, actual
+            ),
+            Matrix2DError::IndexOutOfBounds(index) => {
+                write!(f, "The row index {} is out of bounds.", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Matrix2DError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zeros_ones_and_identity() {
+        let zeros = Matrix2D::zeros(2, 3);
+        assert_eq!((zeros.rows(), zeros.columns()), (2, 3));
+        assert!(zeros.data().iter().all(|&value| value == 0.0));
+
+        let ones = Matrix2D::ones(3, 2);
+        assert_eq!((ones.rows(), ones.columns()), (3, 2));
+        assert!(ones.data().iter().all(|&value| value == 1.0));
+
+        let identity = Matrix2D::identity(3);
+        for r in 0..3 {
+            for c in 0..3 {
+                assert_eq!(identity[(r, c)], if r == c { 1.0 } else { 0.0 });
+            }
+        }
+    }
+
+    #[test]
+    fn extend_rows_appends_block() {
+        let mut matrix = Matrix2D::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        matrix
+            .extend_rows(Matrix2D::new(1, 2, vec![7.0, 8.0]))
+            .unwrap();
+        assert_eq!((matrix.rows(), matrix.columns()), (4, 2));
+        assert_eq!(matrix[(3, 0)], 7.0);
+        assert_eq!(matrix[(3, 1)], 8.0);
+
+        assert_eq!(
+            matrix.extend_rows(Matrix2D::zeros(1, 3)),
+            Err(Matrix2DError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn extend_columns_interleaves_rows() {
+        let mut matrix = Matrix2D::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        matrix
+            .extend_columns(Matrix2D::new(2, 1, vec![5.0, 6.0]))
+            .unwrap();
+        assert_eq!(
+            matrix,
+            Matrix2D::new(2, 3, vec![1.0, 2.0, 5.0, 3.0, 4.0, 6.0])
+        );
+
+        assert_eq!(
+            matrix.extend_columns(Matrix2D::zeros(3, 1)),
+            Err(Matrix2DError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    /// A matrix with its maximum `9` at `(1, 2)` and its minimum `-4` at `(2, 0)`.
+    fn known_matrix() -> Matrix2D<f64> {
+        Matrix2D::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 9.0, -4.0, 8.0, 0.0])
+    }
+
+    #[test]
+    fn max_and_min() {
+        let matrix = known_matrix();
+        assert_eq!(matrix.max(), 9.0);
+        assert_eq!(matrix.min(), -4.0);
+        assert_eq!(matrix.row_max(2), Some(8.0));
+        assert_eq!(matrix.col_max(0), Some(4.0));
+        assert_eq!(matrix.row_max(3), None);
+        assert_eq!(matrix.col_max(3), None);
+        assert_eq!(Matrix2D::zeros(0, 0).max(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn argmax_and_argmin() {
+        let matrix = known_matrix();
+        assert_eq!(matrix.argmax(), Some((1, 2)));
+        assert_eq!(matrix.argmin(), Some((2, 0)));
+        assert_eq!(matrix.argmax_col(1), Some(2));
+        assert_eq!(matrix.argmin_col(2), Some(2));
+        assert_eq!(matrix.argmax_col(3), None);
+        assert_eq!(Matrix2D::zeros(0, 0).argmax(), None);
+    }
+
+    #[test]
+    fn gaussian_elimination_full_rank() {
+        let mut matrix = Matrix2D::new(3, 3, vec![2.0, 1.0, -1.0, -3.0, -1.0, 2.0, -2.0, 1.0, 2.0]);
+        assert_eq!(matrix.gaussian_elimination(), 3);
+        for r in 0..3 {
+            for c in 0..3 {
+                let expected = if r == c { 1.0 } else { 0.0 };
+                assert!((matrix[(r, c)] - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn gaussian_elimination_rank_two() {
+        // The third row is the sum of the first two rows.
+        let mut matrix = Matrix2D::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 5.0, 7.0, 9.0]);
+        assert_eq!(matrix.gaussian_elimination(), 2);
+        assert!((0..3).all(|c| matrix[(2, c)].abs() < 1e-12));
+    }
+
+    #[test]
+    fn dmatrix_round_trip() {
+        let matrix = Matrix2D::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let dmatrix = matrix.to_dmatrix();
+        assert_eq!(
+            dmatrix,
+            DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+        );
+        for r in 0..2 {
+            for c in 0..3 {
+                assert_eq!(dmatrix[(r, c)], matrix[(r, c)]);
+            }
+        }
+        assert_eq!(Matrix2D::from_dmatrix(&dmatrix), matrix);
+    }
+
+    #[test]
+    fn from_rows_builds_matrix() {
+        let matrix =
+            Matrix2D::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+        assert_eq!((matrix.rows(), matrix.columns()), (3, 2));
+        assert_eq!(matrix[(0, 1)], 2.0);
+        assert_eq!(matrix[(2, 0)], 5.0);
+    }
+
+    #[test]
+    fn from_rows_rejects_ragged_rows() {
+        assert_eq!(
+            Matrix2D::from_rows(vec![vec![1.0, 2.0], vec![3.0], vec![5.0, 6.0]]),
+            None
+        );
+    }
+
+    #[test]
+    fn from_columns_matches_from_rows() {
+        let by_columns =
+            Matrix2D::from_columns(vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]).unwrap();
+        let by_rows = Matrix2D::from_rows(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(by_columns, by_rows);
+    }
+
+    #[test]
+    fn from_columns_rejects_ragged_columns() {
+        assert_eq!(
+            Matrix2D::from_columns(vec![vec![1.0, 4.0], vec![2.0]]),
+            None
+        );
+    }
+
+    #[test]
+    fn apply_row_operation_updates_target_row() {
+        let mut matrix = Matrix2D::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        matrix.apply_row_operation(1, 0, 4.0).unwrap();
+        assert_eq!(matrix[(1, 0)], 0.0);
+        assert_eq!(matrix[(1, 1)], -3.0);
+        assert_eq!(matrix[(1, 2)], -6.0);
+        // The source row is unchanged.
+        assert_eq!(matrix[(0, 0)], 1.0);
+        assert_eq!(matrix[(0, 2)], 3.0);
+    }
+
+    #[test]
+    fn apply_row_operation_out_of_bounds() {
+        let mut matrix = Matrix2D::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(
+            matrix.apply_row_operation(2, 0, 1.0),
+            Err(Matrix2DError::IndexOutOfBounds(2))
+        );
+        assert_eq!(
+            matrix.apply_row_operation(0, 3, 1.0),
+            Err(Matrix2DError::IndexOutOfBounds(3))
+        );
+    }
+
+    #[test]
+    fn rank_of_full_deficient_and_zero_matrix() {
+        let full = Matrix2D::new(3, 3, vec![2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0]);
+        assert_eq!(full.rank(), 3);
+
+        // The third row is the sum of the first two rows.
+        let deficient = Matrix2D::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 5.0, 7.0, 9.0]);
+        assert_eq!(deficient.rank(), 2);
+        assert_eq!(deficient[(2, 2)], 9.0);
+
+        assert_eq!(Matrix2D::zeros(3, 3).rank(), 0);
+    }
+
+    #[test]
+    fn mul_vector_and_transpose_mul_vector() {
+        let matrix = Matrix2D::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(matrix.mul_vector(&[1.0, -1.0]), Ok(vec![-1.0, -1.0, -1.0]));
+        assert_eq!(
+            matrix.transpose_mul_vector(&[1.0, 0.0, 2.0]),
+            Ok(vec![11.0, 14.0])
+        );
+    }
+
+    #[test]
+    fn mul_vector_dimension_mismatch() {
+        let matrix = Matrix2D::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(
+            matrix.mul_vector(&[1.0, 2.0, 3.0]),
+            Err(Matrix2DError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            matrix.transpose_mul_vector(&[1.0, 2.0]),
+            Err(Matrix2DError::DimensionMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+}
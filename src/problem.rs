@@ -0,0 +1,1996 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::constraint::{Constraint, Relation};
+use crate::expression::{LinearExpression, LinearTerm};
+use crate::random::Lcg;
+use crate::solution::Solution;
+use crate::tableau::{SimplexResult, Tableau};
+use crate::tableau_builder::SimplexTableauBuilder;
+use crate::variable::Variable;
+
+/// The tolerance used when comparing bounds and values of the problem.
+const EPSILON: f64 = 1e-9;
+
+/// The direction of the optimization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectiveType {
+    /// The objective function is maximized.
+    Maximize,
+
+    /// The objective function is minimized.
+    Minimize,
+}
+
+/// The objective function of a linear program.
+#[derive(Debug, Clone)]
+pub struct Objective {
+    /// The direction of the optimization.
+    objective_type: ObjectiveType,
+
+    /// The expression that is optimized.
+    expression: LinearExpression,
+}
+
+impl Objective {
+    /// Create a new Objective.
+    ///
+    /// # Arguments
+    /// * `objective_type` - The direction of the optimization.
+    /// * `expression` - The expression that is optimized.
+    ///
+    /// # Returns
+    /// A new Objective.
+    pub fn new(objective_type: ObjectiveType, expression: LinearExpression) -> Objective {
+        Objective {
+            objective_type,
+            expression,
+        }
+    }
+
+    /// Create a new Objective that maximizes the expression.
+    ///
+    /// # Arguments
+    /// * `expression` - The expression that is maximized.
+    ///
+    /// # Returns
+    /// A new Objective.
+    pub fn maximize(expression: LinearExpression) -> Objective {
+        Objective::new(ObjectiveType::Maximize, expression)
+    }
+
+    /// Create a new Objective that minimizes the expression.
+    ///
+    /// # Arguments
+    /// * `expression` - The expression that is minimized.
+    ///
+    /// # Returns
+    /// A new Objective.
+    pub fn minimize(expression: LinearExpression) -> Objective {
+        Objective::new(ObjectiveType::Minimize, expression)
+    }
+
+    /// Get the direction of the optimization.
+    ///
+    /// # Returns
+    /// The direction of the optimization.
+    pub fn objective_type(&self) -> ObjectiveType {
+        self.objective_type
+    }
+
+    /// Get the expression that is optimized.
+    ///
+    /// # Returns
+    /// The expression that is optimized.
+    pub fn expression(&self) -> &LinearExpression {
+        &self.expression
+    }
+}
+
+/// An error that can occur when operating on a problem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemError {
+    /// Both problems of a merge have an objective function.
+    ObjectiveConflict,
+
+    /// The dimensions of the given data do not match.
+    DimensionMismatch {
+        /// The expected dimension.
+        expected: usize,
+
+        /// The actual dimension.
+        actual: usize,
+    },
+
+    /// The index of a constraint is out of bounds.
+    ConstraintOutOfBounds(usize),
+}
+
+/// An error that occurs when a problem is built without an objective function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingObjectiveError;
+
+/// The policy that decides which objective function a merged problem gets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectiveMergePolicy {
+    /// Keep the objective function of the first problem.
+    KeepFirst,
+
+    /// Add the objective function of the second problem to the one of the first problem.
+    Add,
+
+    /// Fail if both problems have a non-empty objective function.
+    Error,
+}
+
+/// The kind of variable that was added to turn an inequality into an equality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlackKind {
+    /// A slack variable that was added to a `<=` constraint.
+    Slack,
+
+    /// A surplus variable that was subtracted from a `>=` constraint.
+    Surplus,
+}
+
+/// The result of validating an assignment of the variables of a problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Whether all constraints are satisfied.
+    pub feasible: bool,
+
+    /// Whether all variables have a non-negative value.
+    pub non_negative: bool,
+
+    /// Whether all values of the assignment are finite.
+    pub all_finite: bool,
+
+    /// The indices of the constraints that are not satisfied.
+    pub violated_constraints: Vec<usize>,
+}
+
+/// A mapping from the constraints of a problem to the slack and surplus variables
+/// that were added to them when converting the problem to equality form.
+pub struct SlackMap {
+    /// The added variable for each constraint, indexed by the constraint index.
+    entries: Vec<Option<(Variable, SlackKind)>>,
+}
+
+impl SlackMap {
+    /// Get the variable that was added to a constraint.
+    ///
+    /// # Arguments
+    /// * `constraint` - The index of the constraint.
+    ///
+    /// # Returns
+    /// - `Some(variable)` if a slack or surplus variable was added to the constraint.
+    /// - `None` if the constraint was already an equality or the index is out of bounds.
+    pub fn get(&self, constraint: usize) -> Option<&Variable> {
+        self.entries
+            .get(constraint)
+            .and_then(|entry| entry.as_ref())
+            .map(|(variable, _)| variable)
+    }
+
+    /// Get the kind of variable that was added to a constraint.
+    ///
+    /// # Arguments
+    /// * `constraint` - The index of the constraint.
+    ///
+    /// # Returns
+    /// - `Some(kind)` if a slack or surplus variable was added to the constraint.
+    /// - `None` if the constraint was already an equality or the index is out of bounds.
+    pub fn kind(&self, constraint: usize) -> Option<SlackKind> {
+        self.entries
+            .get(constraint)
+            .and_then(|entry| entry.as_ref())
+            .map(|(_, kind)| *kind)
+    }
+
+    /// Get the number of added variables.
+    ///
+    /// # Returns
+    /// The number of added slack and surplus variables.
+    pub fn len(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.is_some()).count()
+    }
+
+    /// Check if no variables were added.
+    ///
+    /// # Returns
+    /// - `true` if all constraints were already equalities.
+    /// - `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A linear program.
+///
+/// # Note
+/// All variables of the problem are implicitly non-negative.
+#[derive(Debug, Clone)]
+pub struct Problem {
+    /// The objective function of the problem.
+    objective: Objective,
+
+    /// The constraints of the problem.
+    constraints: Vec<Constraint>,
+
+    /// The variables of the problem.
+    variables: Vec<Variable>,
+
+    /// The cached mapping from variable names to their index in `variables`.
+    variable_index: Option<HashMap<String, usize>>,
+}
+
+impl Problem {
+    /// Create a new Problem.
+    ///
+    /// # Arguments
+    /// * `objective` - The objective function of the problem.
+    /// * `constraints` - The constraints of the problem.
+    ///
+    /// # Returns
+    /// A new Problem.
+    ///
+    /// # Note
+    /// The variables of the problem are collected from the objective and the constraints
+    /// in order of their first occurrence.
+    pub fn new(objective: Objective, constraints: Vec<Constraint>) -> Problem {
+        let variables = Problem::collect_variables(&objective, &constraints);
+        Problem {
+            objective,
+            constraints,
+            variables,
+            variable_index: None,
+        }
+    }
+
+    /// Create a new minimization Problem from the canonical form `min c^T x s.t. Ax <= b, x >= 0`.
+    ///
+    /// # Arguments
+    /// * `c` - The objective coefficients.
+    /// * `a` - The constraint matrix.
+    /// * `b` - The rhs vector.
+    /// * `var_names` - The names of the variables.
+    ///
+    /// # Returns
+    /// - `Ok(problem)` with one `<=` constraint per row of `a`.
+    /// - `Err(ProblemError::DimensionMismatch)` if the dimensions do not match.
+    pub fn from_canonical_matrix(
+        c: &DVector<f64>,
+        a: &DMatrix<f64>,
+        b: &DVector<f64>,
+        var_names: Vec<String>,
+    ) -> Result<Problem, ProblemError> {
+        Problem::from_canonical_matrix_with_type(ObjectiveType::Minimize, c, a, b, var_names)
+    }
+
+    /// Create a new maximization Problem from the canonical form `max c^T x s.t. Ax <= b, x >= 0`.
+    ///
+    /// # Arguments
+    /// * `c` - The objective coefficients.
+    /// * `a` - The constraint matrix.
+    /// * `b` - The rhs vector.
+    /// * `var_names` - The names of the variables.
+    ///
+    /// # Returns
+    /// - `Ok(problem)` with one `<=` constraint per row of `a`.
+    /// - `Err(ProblemError::DimensionMismatch)` if the dimensions do not match.
+    pub fn from_canonical_matrix_max(
+        c: &DVector<f64>,
+        a: &DMatrix<f64>,
+        b: &DVector<f64>,
+        var_names: Vec<String>,
+    ) -> Result<Problem, ProblemError> {
+        Problem::from_canonical_matrix_with_type(ObjectiveType::Maximize, c, a, b, var_names)
+    }
+
+    /// Create a new Problem from the canonical form with the given direction of optimization.
+    ///
+    /// # Arguments
+    /// * `objective_type` - The direction of the optimization.
+    /// * `c` - The objective coefficients.
+    /// * `a` - The constraint matrix.
+    /// * `b` - The rhs vector.
+    /// * `var_names` - The names of the variables.
+    ///
+    /// # Returns
+    /// - `Ok(problem)` with one `<=` constraint per row of `a`.
+    /// - `Err(ProblemError::DimensionMismatch)` if the dimensions do not match.
+    fn from_canonical_matrix_with_type(
+        objective_type: ObjectiveType,
+        c: &DVector<f64>,
+        a: &DMatrix<f64>,
+        b: &DVector<f64>,
+        var_names: Vec<String>,
+    ) -> Result<Problem, ProblemError> {
+        // The number of variables is given by the number of names.
+        let n = var_names.len();
+        for actual in [c.len(), a.ncols()] {
+            if actual != n {
+                return Err(ProblemError::DimensionMismatch {
+                    expected: n,
+                    actual,
+                });
+            }
+        }
+        if b.len() != a.nrows() {
+            return Err(ProblemError::DimensionMismatch {
+                expected: a.nrows(),
+                actual: b.len(),
+            });
+        }
+
+        let variables: Vec<Variable> = var_names.iter().map(|name| Variable::new(name)).collect();
+        let expression = |coefficients: Vec<f64>| {
+            let terms = variables
+                .iter()
+                .zip(coefficients)
+                .map(|(variable, coefficient)| LinearTerm::new(variable.clone(), coefficient))
+                .collect();
+            LinearExpression::new(terms)
+        };
+
+        let objective = Objective::new(objective_type, expression(c.iter().copied().collect()));
+        let constraints = a
+            .row_iter()
+            .zip(b.iter())
+            .map(|(row, &rhs)| {
+                let lhs = expression(row.iter().copied().collect());
+                Constraint::new(lhs, Relation::LessThanOrEqual, rhs)
+            })
+            .collect();
+
+        // The variables are set explicitly, so variables with only zero coefficients are kept.
+        let mut problem = Problem::new(objective, constraints);
+        problem.variables = variables;
+        Ok(problem)
+    }
+
+    /// Collect the distinct variables of an objective and constraints.
+    ///
+    /// # Arguments
+    /// * `objective` - The objective function.
+    /// * `constraints` - The constraints.
+    ///
+    /// # Returns
+    /// The distinct variables in order of their first occurrence.
+    fn collect_variables(objective: &Objective, constraints: &[Constraint]) -> Vec<Variable> {
+        let mut variables = objective.expression().variables();
+        for constraint in constraints {
+            for variable in constraint.expression().variables() {
+                if !variables.contains(&variable) {
+                    variables.push(variable);
+                }
+            }
+        }
+        variables
+    }
+
+    /// Get the objective function of the problem.
+    ///
+    /// # Returns
+    /// The objective function of the problem.
+    pub fn objective(&self) -> &Objective {
+        &self.objective
+    }
+
+    /// Get the constraints of the problem.
+    ///
+    /// # Returns
+    /// The constraints of the problem.
+    pub fn constraints(&self) -> &Vec<Constraint> {
+        &self.constraints
+    }
+
+    /// Get the variables of the problem.
+    ///
+    /// # Returns
+    /// The variables of the problem.
+    pub fn variables(&self) -> &Vec<Variable> {
+        &self.variables
+    }
+
+    /// Get the variables of the problem keyed by their name.
+    ///
+    /// # Returns
+    /// A map from the variable names to the variables.
+    ///
+    /// # Note
+    /// The map is built on every call. Use `build_variable_index` together with
+    /// `variable_by_name` for repeated lookups.
+    pub fn variables_by_name(&self) -> HashMap<&str, &Variable> {
+        self.variables
+            .iter()
+            .map(|variable| (variable.name(), variable))
+            .collect()
+    }
+
+    /// Build and cache the index that maps variable names to variables.
+    pub fn build_variable_index(&mut self) {
+        let index = self
+            .variables
+            .iter()
+            .enumerate()
+            .map(|(i, variable)| (variable.name().to_string(), i))
+            .collect();
+        self.variable_index = Some(index);
+    }
+
+    /// Invalidate the cached variable index.
+    ///
+    /// # Note
+    /// This has to be called whenever the variables of the problem change.
+    pub fn invalidate_variable_index(&mut self) {
+        self.variable_index = None;
+    }
+
+    /// Merge the problem with another problem.
+    ///
+    /// # Arguments
+    /// * `other` - The problem to merge with.
+    /// * `policy` - The policy that decides which objective function the merged problem gets.
+    ///
+    /// # Returns
+    /// - `Ok(problem)` with the constraints and variables of both problems.
+    /// - `Err(ProblemError::ObjectiveConflict)` if the policy is `Error`
+    ///   and both problems have a non-empty objective function.
+    ///
+    /// # Note
+    /// The merged problem always keeps the direction of the objective function of `self`.
+    /// When adding objective functions of different directions,
+    /// the objective function of `other` is negated first.
+    pub fn merge(
+        &self,
+        other: &Problem,
+        policy: ObjectiveMergePolicy,
+    ) -> Result<Problem, ProblemError> {
+        let objective_type = self.objective.objective_type;
+        let expression = match policy {
+            ObjectiveMergePolicy::KeepFirst => self.objective.expression.clone(),
+            ObjectiveMergePolicy::Add => {
+                let sign = if other.objective.objective_type == objective_type {
+                    1.0
+                } else {
+                    -1.0
+                };
+                self.objective.expression.clone() + other.objective.expression.clone() * sign
+            }
+            ObjectiveMergePolicy::Error => {
+                if !self.objective.expression.is_empty() && !other.objective.expression.is_empty() {
+                    return Err(ProblemError::ObjectiveConflict);
+                }
+                self.objective.expression.clone() + other.objective.expression.clone()
+            }
+        };
+
+        let mut constraints = self.constraints.clone();
+        constraints.extend(other.constraints.iter().cloned());
+
+        // Variables of the second problem that only occur in its objective function
+        // must not get lost when the objective function is dropped.
+        let mut problem = Problem::new(Objective::new(objective_type, expression), constraints);
+        for variable in &other.variables {
+            if !problem.variables.contains(variable) {
+                problem.variables.push(variable.clone());
+            }
+        }
+        Ok(problem)
+    }
+
+    /// Replace a constraint of the problem.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the constraint to replace.
+    /// * `new_constraint` - The new constraint.
+    ///
+    /// # Returns
+    /// - `Ok(constraint)` with the replaced constraint.
+    /// - `Err(ProblemError::ConstraintOutOfBounds)` if the index is out of bounds.
+    ///
+    /// # Note
+    /// The variables of the problem are recollected, so variables introduced by the new
+    /// constraint are added and variables only used by the old constraint are removed.
+    pub fn replace_constraint(
+        &mut self,
+        index: usize,
+        new_constraint: Constraint,
+    ) -> Result<Constraint, ProblemError> {
+        if index >= self.constraints.len() {
+            return Err(ProblemError::ConstraintOutOfBounds(index));
+        }
+
+        let old_constraint = std::mem::replace(&mut self.constraints[index], new_constraint);
+        self.variables = Problem::collect_variables(&self.objective, &self.constraints);
+        self.invalidate_variable_index();
+        Ok(old_constraint)
+    }
+
+    /// Restrict the problem to the given variables.
+    ///
+    /// # Arguments
+    /// * `variables` - The variables to keep.
+    ///
+    /// # Returns
+    /// The sub-problem that only involves the given variables.
+    ///
+    /// # Note
+    /// A constraint is kept if it references at least one of the given variables
+    /// and does not reference any other variable, so every kept constraint is unchanged.
+    /// The terms of the objective function that reference other variables are dropped.
+    pub fn restrict_to_variables(&self, variables: &[Variable]) -> Problem {
+        let constraints = self
+            .constraints
+            .iter()
+            .filter(|constraint| {
+                let constraint_variables = constraint.expression().variables();
+                !constraint_variables.is_empty()
+                    && constraint_variables
+                        .iter()
+                        .all(|variable| variables.contains(variable))
+            })
+            .cloned()
+            .collect();
+
+        let terms = self
+            .objective
+            .expression
+            .terms()
+            .iter()
+            .filter(|term| variables.contains(term.variable()))
+            .cloned()
+            .collect();
+        let objective = Objective::new(self.objective.objective_type, LinearExpression::new(terms));
+
+        Problem::new(objective, constraints)
+    }
+
+    /// Get a variable of the problem by its name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the variable.
+    ///
+    /// # Returns
+    /// - `Some(variable)` if the problem contains a variable with the given name.
+    /// - `None` otherwise.
+    ///
+    /// # Note
+    /// The cached variable index is used if it was built, otherwise the variables are searched.
+    pub fn variable_by_name(&self, name: &str) -> Option<&Variable> {
+        match &self.variable_index {
+            Some(index) => index.get(name).map(|&i| &self.variables[i]),
+            None => self
+                .variables
+                .iter()
+                .find(|variable| variable.name() == name),
+        }
+    }
+
+    /// Convert the problem to a maximization problem.
+    ///
+    /// # Returns
+    /// The problem with a maximization objective. A minimization objective is negated,
+    /// so the optimal value of the result is the negated optimal value of the problem.
+    /// Returns an unchanged copy if the problem already is a maximization problem.
+    pub fn to_max_form(&self) -> Problem {
+        self.with_objective_type(ObjectiveType::Maximize)
+    }
+
+    /// Convert the problem to a minimization problem.
+    ///
+    /// # Returns
+    /// The problem with a minimization objective. A maximization objective is negated,
+    /// so the optimal value of the result is the negated optimal value of the problem.
+    /// Returns an unchanged copy if the problem already is a minimization problem.
+    pub fn to_min_form(&self) -> Problem {
+        self.with_objective_type(ObjectiveType::Minimize)
+    }
+
+    /// Convert the problem to the given direction of optimization.
+    ///
+    /// # Arguments
+    /// * `objective_type` - The target direction of optimization.
+    ///
+    /// # Returns
+    /// The problem with the objective negated if its direction differs from the target.
+    fn with_objective_type(&self, objective_type: ObjectiveType) -> Problem {
+        let mut problem = self.clone();
+        if self.objective.objective_type != objective_type {
+            let expression = self.objective.expression.clone() * -1.0;
+            problem.objective = Objective::new(objective_type, expression);
+        }
+        problem
+    }
+
+    /// Generate a pseudo-random problem, e.g. for benchmarks.
+    ///
+    /// # Arguments
+    /// * `n_vars` - The number of variables, which are named `x_0`, `x_1`, ...
+    /// * `n_constraints` - The number of constraints.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    /// A maximization problem with objective and constraint coefficients in `[-10, 10)`
+    /// and `<=` constraints with a rhs in `[1, 20)`.
+    /// The same seed always produces the same problem.
+    ///
+    /// # Note
+    /// Since every rhs is positive, the origin is always feasible,
+    /// but the problem may be unbounded.
+    pub fn randomly_generated(n_vars: usize, n_constraints: usize, seed: u64) -> Problem {
+        let mut rng = Lcg::new(seed);
+        let variables = Variable::family("x", n_vars);
+        let random_expression = |rng: &mut Lcg| {
+            let terms = variables
+                .iter()
+                .map(|variable| LinearTerm::new(variable.clone(), rng.next_in_range(-10.0, 10.0)))
+                .collect();
+            LinearExpression::new(terms)
+        };
+
+        let objective = Objective::maximize(random_expression(&mut rng));
+        let constraints = (0..n_constraints)
+            .map(|_| {
+                let expression = random_expression(&mut rng);
+                let rhs = rng.next_in_range(1.0, 20.0);
+                Constraint::new(expression, Relation::LessThanOrEqual, rhs)
+            })
+            .collect();
+        Problem::new(objective, constraints)
+    }
+
+    /// Replace the objective coefficients with pseudo-random values.
+    ///
+    /// # Arguments
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    /// The problem with the same constraints and direction of optimization,
+    /// but with a coefficient in `[-10, 10)` for every variable in the objective function.
+    /// The same seed always produces the same objective function.
+    pub fn with_random_objective(&self, seed: u64) -> Problem {
+        let mut rng = Lcg::new(seed);
+        let terms = self
+            .variables
+            .iter()
+            .map(|variable| LinearTerm::new(variable.clone(), rng.next_in_range(-10.0, 10.0)))
+            .collect();
+
+        let mut problem = self.clone();
+        problem.objective =
+            Objective::new(self.objective.objective_type, LinearExpression::new(terms));
+        problem
+    }
+
+    /// Evaluate the objective function at the given assignment.
+    ///
+    /// # Arguments
+    /// * `assignment` - The values of the variables, keyed by variable name.
+    ///
+    /// # Returns
+    /// The value of the objective function, which is never negated,
+    /// regardless of the direction of the optimization.
+    pub fn objective_value_at(&self, assignment: &HashMap<String, f64>) -> f64 {
+        self.objective.expression.evaluate(assignment)
+    }
+
+    /// Validate an assignment of the variables, e.g. a solution returned by a solver.
+    ///
+    /// # Arguments
+    /// * `solution` - The values of the variables, keyed by variable name.
+    ///
+    /// # Returns
+    /// A report on the feasibility, the non-negativity and the finiteness of the assignment.
+    ///
+    /// # Note
+    /// Variables that are not part of the assignment are treated as zero.
+    /// `feasible` only covers the constraints, the implicit non-negativity of the variables
+    /// is reported separately by `non_negative`.
+    pub fn validate_solution(&self, solution: &HashMap<String, f64>) -> ValidationReport {
+        let violated_constraints: Vec<usize> = self
+            .constraints
+            .iter()
+            .enumerate()
+            .filter(|(_, constraint)| !constraint.satisfied_by(solution))
+            .map(|(i, _)| i)
+            .collect();
+        let non_negative = self
+            .variables
+            .iter()
+            .all(|variable| *solution.get(variable.name()).unwrap_or(&0.0) >= -EPSILON);
+        let all_finite = solution.values().all(|value| value.is_finite());
+
+        ValidationReport {
+            feasible: violated_constraints.is_empty(),
+            non_negative,
+            all_finite,
+            violated_constraints,
+        }
+    }
+
+    /// Get the constraints that are binding at the given assignment.
+    ///
+    /// # Arguments
+    /// * `assignment` - The values of the variables, keyed by variable name.
+    ///
+    /// # Returns
+    /// The constraints whose left hand side equals the right hand side, in their original order.
+    pub fn binding_constraints(&self, assignment: &HashMap<String, f64>) -> Vec<&Constraint> {
+        self.constraints
+            .iter()
+            .filter(|constraint| constraint.is_binding_at(assignment))
+            .collect()
+    }
+
+    /// Count the constraints that are binding at an optimal solution.
+    ///
+    /// # Arguments
+    /// * `solution` - The optimal solution of the problem.
+    ///
+    /// # Returns
+    /// The number of constraints whose left hand side equals the right hand side.
+    ///
+    /// # Note
+    /// At a non-degenerate optimal vertex of a problem with `n` variables, exactly `n`
+    /// constraints and non-negativity bounds are binding. More binding constraints indicate
+    /// a degenerate optimal basis.
+    pub fn count_binding_constraints_at_optimum(&self, solution: &Solution) -> usize {
+        self.binding_constraints(&solution.to_assignment()).len()
+    }
+
+    /// Check if the origin, i.e. setting all variables to zero, is feasible.
+    ///
+    /// # Returns
+    /// - `true` if every constraint is satisfied at the origin.
+    /// - `false` otherwise.
+    ///
+    /// # Note
+    /// If the origin is feasible, the slack variables form a feasible initial basis
+    /// and phase 1 of the two phase simplex method is not needed.
+    pub fn has_feasible_origin(&self) -> bool {
+        self.constraints
+            .iter()
+            .all(|constraint| match constraint.relation() {
+                Relation::LessThanOrEqual => constraint.rhs() >= 0.0,
+                Relation::GreaterThanOrEqual => constraint.rhs() <= 0.0,
+                Relation::Equal => constraint.rhs() == 0.0,
+            })
+    }
+
+    /// Detect trivial infeasibilities without solving the problem.
+    ///
+    /// # Returns
+    /// - `Some(explanation)` if the problem is infeasible for structural reasons.
+    /// - `None` if no structural infeasibility was found, which does not imply feasibility.
+    ///
+    /// # Note
+    /// Constraints without variables are checked directly, e.g. `0 >= 1`.
+    /// Constraints with a single variable are turned into bounds of that variable,
+    /// which are combined with the implicit non-negativity of the variable, so that
+    /// contradictory bounds like `x >= 5` and `x <= 3` are detected.
+    pub fn detect_infeasibility_structural(&self) -> Option<String> {
+        let mut bounds: HashMap<String, (f64, f64)> = HashMap::new();
+
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            let variables = constraint.expression().variables();
+            let coefficient = match variables.as_slice() {
+                [variable] => constraint.expression().coefficient(variable),
+                [] => 0.0,
+                _ => continue,
+            };
+
+            if coefficient.abs() <= EPSILON {
+                let rhs = constraint.rhs();
+                let satisfied = match constraint.relation() {
+                    Relation::LessThanOrEqual => rhs >= -EPSILON,
+                    Relation::GreaterThanOrEqual => rhs <= EPSILON,
+                    Relation::Equal => rhs.abs() <= EPSILON,
+                };
+                if !satisfied {
+                    return Some(format!(
+                        "Constraint c{} has no variables and requires 0 {} {}.",
+                        i,
+                        constraint.relation(),
+                        rhs
+                    ));
+                }
+                continue;
+            }
+
+            // Dividing by a negative coefficient reverses the relation.
+            let bound = constraint.rhs() / coefficient;
+            let relation = if coefficient < 0.0 {
+                constraint.relation().reversed()
+            } else {
+                constraint.relation()
+            };
+            let name = variables[0].name();
+            let (lower, upper) = bounds
+                .entry(name.to_string())
+                .or_insert((0.0, f64::INFINITY));
+            if relation != Relation::LessThanOrEqual {
+                *lower = lower.max(bound);
+            }
+            if relation != Relation::GreaterThanOrEqual {
+                *upper = upper.min(bound);
+            }
+
+            if *lower > *upper + EPSILON {
+                return Some(format!(
+                    "Variable {} has a lower bound of {} but an upper bound of {}.",
+                    name, lower, upper
+                ));
+            }
+        }
+
+        None
+    }
+
+    /// Find a feasible point of the problem.
+    ///
+    /// # Returns
+    /// - `Some(point)` with the values of the variables, keyed by variable name.
+    /// - `None` if the problem is infeasible.
+    ///
+    /// # Note
+    /// The point is found by solving the phase 1 problem of the two phase simplex method,
+    /// so it is a vertex of the feasible region.
+    pub fn feasible_point(&self) -> Option<HashMap<String, f64>> {
+        let (mut tableau, artificial_info) =
+            SimplexTableauBuilder::from_problem(self).build_phase1();
+
+        // The phase 1 problem is bounded by zero, so it is always solved to optimality.
+        if tableau.solve() != SimplexResult::Optimal {
+            return None;
+        }
+        if !tableau.is_phase1_complete(artificial_info.columns()).ok()? {
+            return None;
+        }
+
+        // The variables of the problem occupy the first columns of the tableau.
+        let point = self
+            .variables
+            .iter()
+            .enumerate()
+            .map(|(col, variable)| (variable.name().to_string(), tableau.column_value(col)))
+            .collect();
+        Some(point)
+    }
+
+    /// Solve the problem with the two phase simplex method.
+    ///
+    /// # Returns
+    /// - `Some((tableau, result))` with the final tableau and the result of phase 2.
+    /// - `None` if the problem is infeasible.
+    ///
+    /// # Note
+    /// After phase 1, artificial variables that remain basic at zero level are pivoted out
+    /// of the basis, and constraint rows in which this is not possible are redundant and dropped.
+    /// The phase 2 tableau consists of the remaining rows without the artificial columns,
+    /// with the objective row re-priced for the basis found in phase 1.
+    fn solve_two_phase(&self) -> Option<(Tableau, SimplexResult)> {
+        let (mut tableau, artificial_info) = SimplexTableauBuilder::from_problem(self).build();
+        if !artificial_info.is_phase1_required() {
+            let result = tableau.solve();
+            return Some((tableau, result));
+        }
+        let costs: Vec<f64> = tableau.objective_coefficients().iter().copied().collect();
+
+        let (mut phase1, _) = SimplexTableauBuilder::from_problem(self).build_phase1();
+        phase1.solve();
+        if !phase1.is_phase1_complete(artificial_info.columns()).ok()? {
+            #[cfg(feature = "logging")]
+            log::debug!("Phase 1 finished: the problem is infeasible");
+            return None;
+        }
+        #[cfg(feature = "logging")]
+        log::debug!(
+            "Phase 1 finished after {} pivots, switching to phase 2",
+            phase1.total_pivot_count()
+        );
+
+        // The artificial columns are the last columns before the rhs column.
+        let artificials = artificial_info.columns();
+        let first_artificial = artificials[0];
+        let mut redundant_rows = Vec::new();
+        for row in 0..phase1.rows() - 1 {
+            if !artificials.contains(&phase1.basis()?[row]) {
+                continue;
+            }
+            let column = (0..first_artificial)
+                .find(|&c| phase1.get_matrix()[(row, c)].abs() > phase1.tolerance());
+            match column {
+                // The rhs of the row is zero, so the pivot keeps the tableau feasible.
+                Some(column) => phase1.gaussian_pivot(row, column),
+                None => redundant_rows.push(row),
+            }
+        }
+
+        let keep_row = |row: &usize| !redundant_rows.contains(row);
+        let basis = (0..phase1.rows() - 1)
+            .filter(keep_row)
+            .map(|row| phase1.basis().map(|basis| basis[row]))
+            .collect::<Option<Vec<usize>>>()?;
+        let row_names = (0..phase1.rows())
+            .filter(keep_row)
+            .map(|row| phase1.row_names()[row].clone())
+            .collect();
+        let column_names = phase1
+            .column_names()
+            .iter()
+            .enumerate()
+            .filter(|(c, _)| !artificials.contains(c))
+            .map(|(_, name)| name.clone())
+            .collect();
+        let matrix = phase1
+            .get_matrix()
+            .clone()
+            .remove_rows_at(&redundant_rows)
+            .remove_columns(first_artificial, artificials.len());
+
+        let mut tableau = Tableau::new(matrix, row_names, column_names);
+        tableau.set_basis(basis);
+        tableau
+            .update_objective_row(&costs[..first_artificial])
+            .ok()?;
+        let result = tableau.solve();
+        #[cfg(feature = "logging")]
+        log::debug!(
+            "Phase 2 finished after {} pivots: {:?}",
+            tableau.total_pivot_count(),
+            result
+        );
+        Some((tableau, result))
+    }
+
+    /// Check if the problem has a unique optimal solution.
+    ///
+    /// # Returns
+    /// - `true` if the optimal basis is non-degenerate and every non-basic column
+    ///   has a strictly positive reduced cost.
+    /// - `false` otherwise, including infeasible and unbounded problems.
+    ///
+    /// # Note
+    /// This is a heuristic, not a proof. A positive reduced cost for every non-basic column
+    /// rules out alternative optima only if the optimal basis is non-degenerate.
+    /// If the optimal basis is degenerate, `false` is returned, even though the optimal
+    /// solution may still be unique.
+    pub fn has_unique_solution(&self) -> bool {
+        let Some((tableau, SimplexResult::Optimal)) = self.solve_two_phase() else {
+            return false;
+        };
+        let Some(basis) = tableau.basis() else {
+            return false;
+        };
+
+        let tolerance = tableau.tolerance();
+        let non_degenerate = tableau.rhs_vector().iter().all(|value| *value > tolerance);
+        let no_alternative_optimum = tableau
+            .objective_coefficients()
+            .iter()
+            .enumerate()
+            .filter(|(c, _)| !basis.contains(c))
+            .all(|(_, value)| *value > tolerance);
+        non_degenerate && no_alternative_optimum
+    }
+
+    /// Construct the phase 1 feasibility problem.
+    ///
+    /// # Returns
+    /// A problem in equality form with an artificial variable `a_i` added to every constraint `i`,
+    /// which minimizes the sum of the artificial variables.
+    /// The problem is feasible if and only if the optimal value of the feasibility problem is zero.
+    ///
+    /// # Note
+    /// Constraints with a negative rhs are flipped first, so that setting all
+    /// artificial variables to the rhs values is a feasible starting point.
+    /// Slack and surplus variables are added as in `to_equality_form`.
+    /// The names of the artificial variables are made unique in the same way as
+    /// the names of the slack variables.
+    pub fn feasibility_lp(&self) -> Problem {
+        let normalized = self
+            .constraints
+            .iter()
+            .map(|constraint| {
+                if constraint.rhs() < 0.0 {
+                    constraint.flip()
+                } else {
+                    constraint.clone()
+                }
+            })
+            .collect();
+        let (equality_form, _) =
+            Problem::new(self.objective.clone(), normalized).to_equality_form();
+
+        let mut constraints = Vec::with_capacity(equality_form.constraints.len());
+        let mut artificials = Vec::with_capacity(equality_form.constraints.len());
+        for (i, constraint) in equality_form.constraints.iter().enumerate() {
+            let artificial = Problem::unused_variable(format!("a_{}", i), &equality_form.variables);
+            let expression =
+                constraint.expression().clone() + LinearTerm::new(artificial.clone(), 1.0);
+            constraints.push(Constraint::new(
+                expression,
+                Relation::Equal,
+                constraint.rhs(),
+            ));
+            artificials.push(artificial);
+        }
+
+        let terms = artificials
+            .iter()
+            .map(|artificial| LinearTerm::new(artificial.clone(), 1.0))
+            .collect();
+        let mut problem = Problem::new(
+            Objective::minimize(LinearExpression::new(terms)),
+            constraints,
+        );
+
+        // Keep the original variables first and the artificial variables last.
+        let mut variables = equality_form.variables;
+        variables.extend(artificials);
+        problem.variables = variables;
+        problem
+    }
+
+    /// Extract the constraint matrix and the rhs vector of the problem.
+    ///
+    /// # Returns
+    /// A tuple `(a, b, names)` with one row of `a` and one entry of `b` per constraint,
+    /// and one column of `a` per variable. `names` contains the names of the variables
+    /// in the order of the columns, which is sorted by name.
+    ///
+    /// # Note
+    /// All constraints are assumed to be `<=` constraints, the relations are ignored.
+    /// Convert the problem with `to_equality_form` first if the relations matter.
+    pub fn constraint_matrix(&self) -> (DMatrix<f64>, DVector<f64>, Vec<String>) {
+        let variables = self.sorted_variables();
+        let a = DMatrix::from_fn(self.constraints.len(), variables.len(), |r, c| {
+            self.constraints[r].expression().coefficient(&variables[c])
+        });
+        let b = DVector::from_iterator(
+            self.constraints.len(),
+            self.constraints.iter().map(|constraint| constraint.rhs()),
+        );
+        let names = variables.iter().map(|v| v.name().to_string()).collect();
+        (a, b, names)
+    }
+
+    /// Extract the coefficients of the objective function as a dense vector.
+    ///
+    /// # Returns
+    /// A tuple `(c, names)` with one entry of `c` per variable, and the names of the
+    /// variables in the order of the entries. The variables are sorted by name,
+    /// which is the same order as used by `constraint_matrix`.
+    ///
+    /// # Note
+    /// The coefficients are never negated, regardless of the direction of the optimization.
+    pub fn objective_vector(&self) -> (DVector<f64>, Vec<String>) {
+        let variables = self.sorted_variables();
+        let c = DVector::from_vec(self.objective.expression.to_vector(&variables));
+        let names = variables.iter().map(|v| v.name().to_string()).collect();
+        (c, names)
+    }
+
+    /// Get the variables of the problem sorted by name.
+    ///
+    /// # Returns
+    /// The variables of the problem sorted by name, which is the deterministic
+    /// variable order of the dense matrix representation.
+    fn sorted_variables(&self) -> Vec<Variable> {
+        let mut variables = self.variables.clone();
+        variables.sort_by(|a, b| a.name().cmp(b.name()));
+        variables
+    }
+
+    /// Convert the problem into the LP file format.
+    ///
+    /// # Returns
+    /// The problem in the LP file format.
+    ///
+    /// # Note
+    /// The constraints are named `c0`, `c1`, ... and every constraint is written on a single line.
+    /// No bounds section is written, since all variables are non-negative by default.
+    pub fn to_lp_string(&self) -> String {
+        let mut lp = String::new();
+        match self.objective.objective_type {
+            ObjectiveType::Maximize => lp.push_str("Maximize\n"),
+            ObjectiveType::Minimize => lp.push_str("Minimize\n"),
+        }
+        lp.push_str(&format!(
+            " obj:{}\n",
+            Problem::lp_expression(&self.objective.expression)
+        ));
+
+        lp.push_str("Subject To\n");
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            lp.push_str(&format!(
+                " c{}:{} {} {}\n",
+                i,
+                Problem::lp_expression(constraint.expression()),
+                constraint.relation(),
+                constraint.rhs()
+            ));
+        }
+
+        lp.push_str("End\n");
+        lp
+    }
+
+    /// Write the problem to a file in the LP file format.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the file.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the file was written.
+    /// - `Err(error)` if the file could not be written.
+    pub fn to_lp_file<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_lp_string())
+    }
+
+    /// Convert the problem into the AMPL data file format.
+    ///
+    /// # Returns
+    /// The problem as an AMPL `.dat` string, declaring the sets `VARS` and `CONS`
+    /// and the parameters `c` (objective costs), `b` (rhs) and `A` (constraint coefficients).
+    ///
+    /// # Note
+    /// The constraints are named `c0`, `c1`, ... as in the LP file format.
+    /// The relations and the direction of the optimization are not part of the data,
+    /// they have to be declared in the AMPL model file.
+    pub fn to_ampl_dat_string(&self) -> String {
+        let names: Vec<&str> = self.variables.iter().map(|v| v.name()).collect();
+        let mut dat = String::new();
+
+        dat.push_str(&format!("set VARS := {} ;\n", names.join(" ")));
+        let constraint_names: Vec<String> = (0..self.constraints.len())
+            .map(|i| format!("c{}", i))
+            .collect();
+        dat.push_str(&format!("set CONS := {} ;\n", constraint_names.join(" ")));
+
+        dat.push_str("\nparam c :=\n");
+        for variable in &self.variables {
+            dat.push_str(&format!(
+                " {} {}\n",
+                variable,
+                self.objective.expression.coefficient(variable)
+            ));
+        }
+        dat.push_str(";\n");
+
+        dat.push_str("\nparam b :=\n");
+        for (name, constraint) in constraint_names.iter().zip(&self.constraints) {
+            dat.push_str(&format!(" {} {}\n", name, constraint.rhs()));
+        }
+        dat.push_str(";\n");
+
+        dat.push_str(&format!("\nparam A : {} :=\n", names.join(" ")));
+        for (name, constraint) in constraint_names.iter().zip(&self.constraints) {
+            dat.push_str(&format!(" {}", name));
+            for variable in &self.variables {
+                dat.push_str(&format!(
+                    " {}",
+                    constraint.expression().coefficient(variable)
+                ));
+            }
+            dat.push('\n');
+        }
+        dat.push_str(";\n");
+        dat
+    }
+
+    /// Format an expression for the LP file format.
+    ///
+    /// # Arguments
+    /// * `expression` - The expression to format.
+    ///
+    /// # Returns
+    /// The terms of the expression, each of the form ` + 3 x` or ` - 3 x`.
+    fn lp_expression(expression: &LinearExpression) -> String {
+        expression
+            .terms()
+            .iter()
+            .map(|term| {
+                if term.coefficient() < 0.0 {
+                    format!(" - {} {}", -term.coefficient(), term.variable())
+                } else {
+                    format!(" + {} {}", term.coefficient(), term.variable())
+                }
+            })
+            .collect()
+    }
+
+    /// Create a variable whose name is not used by any of the given variables.
+    ///
+    /// # Arguments
+    /// * `name` - The preferred name of the variable.
+    /// * `variables` - The variables whose names are taken.
+    ///
+    /// # Returns
+    /// A variable with the preferred name, followed by as many underscores as are needed
+    /// to make the name unique.
+    pub fn unused_variable(mut name: String, variables: &[Variable]) -> Variable {
+        while variables.iter().any(|variable| variable.name() == name) {
+            name.push('_');
+        }
+        Variable::new(&name)
+    }
+
+    /// Convert the problem to equality form.
+    ///
+    /// # Returns
+    /// The problem in which all constraints are equalities,
+    /// and a mapping from the constraints to the added variables.
+    ///
+    /// # Note
+    /// A slack variable `s_i` is added to every `<=` constraint `i`,
+    /// and a surplus variable `s_i` is subtracted from every `>=` constraint `i`.
+    /// If the problem already has a variable named `s_i`, underscores are appended
+    /// to the name of the added variable until it is unique.
+    pub fn to_equality_form(&self) -> (Problem, SlackMap) {
+        let mut constraints = Vec::with_capacity(self.constraints.len());
+        let mut entries = Vec::with_capacity(self.constraints.len());
+
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            let (coefficient, kind) = match constraint.relation() {
+                Relation::LessThanOrEqual => (1.0, SlackKind::Slack),
+                Relation::GreaterThanOrEqual => (-1.0, SlackKind::Surplus),
+                Relation::Equal => {
+                    constraints.push(constraint.clone());
+                    entries.push(None);
+                    continue;
+                }
+            };
+
+            // Add the slack or surplus variable to the left hand side.
+            let variable = Problem::unused_variable(format!("s_{}", i), &self.variables);
+            let expression =
+                constraint.expression().clone() + LinearTerm::new(variable.clone(), coefficient);
+            constraints.push(Constraint::new(
+                expression,
+                Relation::Equal,
+                constraint.rhs(),
+            ));
+            entries.push(Some((variable, kind)));
+        }
+
+        let problem = Problem::new(self.objective.clone(), constraints);
+        (problem, SlackMap { entries })
+    }
+}
+
+/// A builder that assembles a problem step by step.
+#[derive(Default)]
+pub struct ProblemBuilder {
+    /// The objective function of the problem.
+    objective: Option<Objective>,
+
+    /// The constraints of the problem.
+    constraints: Vec<Constraint>,
+}
+
+impl ProblemBuilder {
+    /// Create a new ProblemBuilder without objective function and constraints.
+    ///
+    /// # Returns
+    /// A new ProblemBuilder.
+    pub fn new() -> ProblemBuilder {
+        ProblemBuilder::default()
+    }
+
+    /// Set the objective function of the problem.
+    ///
+    /// # Arguments
+    /// * `objective` - The objective function.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn objective(mut self, objective: Objective) -> ProblemBuilder {
+        self.objective = Some(objective);
+        self
+    }
+
+    /// Add a constraint to the problem.
+    ///
+    /// # Arguments
+    /// * `constraint` - The constraint.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn constraint(mut self, constraint: Constraint) -> ProblemBuilder {
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// Build the problem.
+    ///
+    /// # Returns
+    /// - `Ok(problem)` if an objective function was set.
+    /// - `Err(MissingObjectiveError)` otherwise.
+    pub fn build(self) -> Result<Problem, MissingObjectiveError> {
+        let objective = self.objective.ok_or(MissingObjectiveError)?;
+        Ok(Problem::new(objective, self.constraints))
+    }
+}
+
+/// Implement the Display trait for Problem.
+///
+/// # Note
+/// The problem is displayed in its algebraic form, one constraint per line.
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.objective.objective_type {
+            ObjectiveType::Maximize => writeln!(f, "max {}", self.objective.expression)?,
+            ObjectiveType::Minimize => writeln!(f, "min {}", self.objective.expression)?,
+        }
+
+        writeln!(f, "s.t.")?;
+        for constraint in &self.constraints {
+            writeln!(f, "    {}", constraint)?;
+        }
+        Ok(())
+    }
+}
+
+/// Implement the Display trait for ProblemError.
+impl Display for ProblemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ProblemError::ObjectiveConflict => {
+                write!(f, "Both problems of the merge have an objective function.")
+            }
+            ProblemError::DimensionMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Expected a dimension of {}, but got {}.",
+                    expected, actual
+                )
+            }
+            ProblemError::ConstraintOutOfBounds(index) => {
+                write!(f, "Constraint index {} is out of bounds.", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProblemError {}
+
+/// Implement the Display trait for MissingObjectiveError.
+impl Display for MissingObjectiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ProblemBuilder requires an objective function before building"
+        )
+    }
+}
+
+impl std::error::Error for MissingObjectiveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The textbook problem `max 3x + 5y s.t. x <= 4, 2y <= 12, 3x + 2y <= 18`.
+    fn textbook_problem() -> Problem {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        Problem::new(
+            Objective::maximize(x.clone() * 3.0 + y.clone() * 5.0),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::LessThanOrEqual, 4.0),
+                Constraint::new((y.clone() * 2.0).into(), Relation::LessThanOrEqual, 12.0),
+                Constraint::new(x * 3.0 + y * 2.0, Relation::LessThanOrEqual, 18.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn to_equality_form_adds_slack_and_surplus() {
+        let x = Variable::new("x");
+        let problem = Problem::new(
+            Objective::minimize((x.clone() * 1.0).into()),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::LessThanOrEqual, 4.0),
+                Constraint::new((x.clone() * 1.0).into(), Relation::GreaterThanOrEqual, 1.0),
+                Constraint::new((x.clone() * 1.0).into(), Relation::Equal, 2.0),
+            ],
+        );
+        let (equality_form, slacks) = problem.to_equality_form();
+
+        assert!(equality_form
+            .constraints()
+            .iter()
+            .all(|constraint| constraint.relation() == Relation::Equal));
+        assert_eq!(slacks.kind(0), Some(SlackKind::Slack));
+        assert_eq!(slacks.kind(1), Some(SlackKind::Surplus));
+        assert_eq!(slacks.kind(2), None);
+
+        let slack = slacks.get(0).unwrap();
+        let surplus = slacks.get(1).unwrap();
+        assert_eq!(
+            equality_form.constraints()[0]
+                .expression()
+                .coefficient(slack),
+            1.0
+        );
+        assert_eq!(
+            equality_form.constraints()[1]
+                .expression()
+                .coefficient(surplus),
+            -1.0
+        );
+    }
+
+    #[test]
+    fn to_equality_form_avoids_name_collisions() {
+        let s = [Variable::new("s_0"), Variable::new("s_1")];
+        let problem = Problem::new(
+            Objective::maximize(s[0].clone() * 1.0 + s[1].clone() * 1.0),
+            vec![Constraint::new(
+                s[0].clone() * 1.0 + s[1].clone() * 1.0,
+                Relation::LessThanOrEqual,
+                1.0,
+            )],
+        );
+        let (equality_form, slacks) = problem.to_equality_form();
+
+        assert!(!s.contains(slacks.get(0).unwrap()));
+        assert_eq!(equality_form.variables().len(), 3);
+        assert_eq!(equality_form.constraints()[0].expression().len(), 3);
+    }
+
+    #[test]
+    fn feasibility_lp_has_one_artificial_per_constraint() {
+        let problem = textbook_problem();
+        let feasibility = problem.feasibility_lp();
+
+        assert_eq!(
+            feasibility.objective().objective_type(),
+            ObjectiveType::Minimize
+        );
+        let artificials = feasibility.objective().expression().variables();
+        assert_eq!(artificials.len(), problem.constraints().len());
+        for (constraint, artificial) in feasibility.constraints().iter().zip(&artificials) {
+            assert_eq!(constraint.relation(), Relation::Equal);
+            assert_eq!(constraint.expression().coefficient(artificial), 1.0);
+        }
+    }
+
+    #[test]
+    fn feasibility_lp_avoids_name_collisions() {
+        let a = Variable::family("a", 1);
+        let problem = Problem::new(
+            Objective::maximize((a[0].clone() * 1.0).into()),
+            vec![Constraint::new(
+                (a[0].clone() * 1.0).into(),
+                Relation::Equal,
+                1.0,
+            )],
+        );
+        let feasibility = problem.feasibility_lp();
+
+        let artificials = feasibility.objective().expression().variables();
+        assert_eq!(artificials.len(), 1);
+        assert_ne!(artificials[0], a[0]);
+        assert_eq!(feasibility.variables().len(), 2);
+    }
+
+    #[test]
+    fn to_equality_form_keeps_textbook_variables() {
+        let (equality_form, slacks) = textbook_problem().to_equality_form();
+        assert_eq!(slacks.len(), 3);
+        assert_eq!(equality_form.variables().len(), 5);
+    }
+
+    #[test]
+    fn variables_by_name_contains_all_variables() {
+        let problem = textbook_problem();
+        let variables = problem.variables_by_name();
+        assert_eq!(variables.len(), 2);
+        assert_eq!(variables["x"], &Variable::new("x"));
+        assert_eq!(variables["y"], &Variable::new("y"));
+    }
+
+    /// The problem `min u + v s.t. u + v >= 1`, which shares no variables with the textbook problem.
+    fn disjoint_problem() -> Problem {
+        let u = Variable::new("u");
+        let v = Variable::new("v");
+        Problem::new(
+            Objective::minimize(u.clone() * 1.0 + v.clone() * 1.0),
+            vec![Constraint::new(
+                u * 1.0 + v * 1.0,
+                Relation::GreaterThanOrEqual,
+                1.0,
+            )],
+        )
+    }
+
+    #[test]
+    fn merge_disjoint_problems() {
+        let first = textbook_problem();
+        let second = disjoint_problem();
+        let merged = first
+            .merge(&second, ObjectiveMergePolicy::KeepFirst)
+            .unwrap();
+
+        assert_eq!(merged.constraints().len(), 4);
+        assert_eq!(merged.variables().len(), 4);
+        assert_eq!(merged.objective().objective_type(), ObjectiveType::Maximize);
+
+        // The minimization objective is negated when it is added to a maximization.
+        let added = first.merge(&second, ObjectiveMergePolicy::Add).unwrap();
+        assert_eq!(
+            added
+                .objective()
+                .expression()
+                .coefficient(&Variable::new("u")),
+            -1.0
+        );
+
+        assert!(matches!(
+            first.merge(&second, ObjectiveMergePolicy::Error),
+            Err(ProblemError::ObjectiveConflict)
+        ));
+    }
+
+    #[test]
+    fn restrict_to_variables_keeps_subset() {
+        let problem = textbook_problem();
+        let x = Variable::new("x");
+        let restricted = problem.restrict_to_variables(std::slice::from_ref(&x));
+
+        // Only `x <= 4` references no other variable than `x`.
+        assert_eq!(restricted.constraints().len(), 1);
+        assert_eq!(restricted.constraints()[0].rhs(), 4.0);
+        for constraint in restricted.constraints() {
+            assert!(constraint.expression().variables().iter().all(|v| v == &x));
+        }
+        assert_eq!(restricted.variables(), &vec![x]);
+        assert_eq!(restricted.objective().expression().len(), 1);
+    }
+
+    #[test]
+    fn feasible_point_of_feasible_problem() {
+        let problem = Problem::new(
+            Objective::maximize((Variable::new("x") * 1.0).into()),
+            vec![
+                Constraint::new(
+                    (Variable::new("x") * 1.0).into(),
+                    Relation::GreaterThanOrEqual,
+                    1.0,
+                ),
+                Constraint::new(
+                    (Variable::new("x") * 1.0).into(),
+                    Relation::LessThanOrEqual,
+                    3.0,
+                ),
+            ],
+        );
+        let point = problem.feasible_point().unwrap();
+        assert!(problem.constraints().iter().all(|c| c.satisfied_by(&point)));
+        assert!(point["x"] >= 1.0 - 1e-9);
+    }
+
+    #[test]
+    fn feasible_point_of_infeasible_problem() {
+        let x = Variable::new("x");
+        let problem = Problem::new(
+            Objective::maximize((x.clone() * 1.0).into()),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::GreaterThanOrEqual, 1.0),
+                Constraint::new((x * 1.0).into(), Relation::LessThanOrEqual, 0.0),
+            ],
+        );
+        assert_eq!(problem.feasible_point(), None);
+    }
+
+    #[test]
+    fn missing_objective_error_is_boxed_error() {
+        fn build() -> Result<Problem, Box<dyn std::error::Error>> {
+            Ok(ProblemBuilder::new().build()?)
+        }
+
+        let error = build().err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "ProblemBuilder requires an objective function before building"
+        );
+    }
+
+    /// The optimal solution `x = 2, y = 6` of the textbook problem.
+    fn textbook_optimum() -> HashMap<String, f64> {
+        HashMap::from([("x".to_string(), 2.0), ("y".to_string(), 6.0)])
+    }
+
+    #[test]
+    fn objective_value_at_optimum() {
+        let problem = textbook_problem();
+        assert_eq!(problem.objective_value_at(&textbook_optimum()), 36.0);
+        assert_eq!(problem.objective_value_at(&HashMap::new()), 0.0);
+    }
+
+    #[test]
+    fn to_min_form_negates_maximization() {
+        let problem = textbook_problem();
+        let min_form = problem.to_min_form();
+        let optimum = textbook_optimum();
+
+        assert_eq!(
+            min_form.objective().objective_type(),
+            ObjectiveType::Minimize
+        );
+        assert_eq!(min_form.objective_value_at(&optimum), -36.0);
+        assert_eq!(min_form.constraints().len(), problem.constraints().len());
+    }
+
+    #[test]
+    fn to_max_form_negates_minimization() {
+        let min_form = textbook_problem().to_min_form();
+        let max_form = min_form.to_max_form();
+
+        assert_eq!(
+            max_form.objective().objective_type(),
+            ObjectiveType::Maximize
+        );
+        assert_eq!(max_form.objective_value_at(&textbook_optimum()), 36.0);
+    }
+
+    #[test]
+    fn to_max_form_keeps_maximization() {
+        let problem = textbook_problem();
+        let max_form = problem.to_max_form();
+
+        assert_eq!(
+            max_form.objective().objective_type(),
+            ObjectiveType::Maximize
+        );
+        assert_eq!(max_form.objective_value_at(&textbook_optimum()), 36.0);
+    }
+
+    fn objective_coefficients(problem: &Problem) -> Vec<f64> {
+        problem
+            .variables()
+            .iter()
+            .map(|variable| problem.objective().expression().coefficient(variable))
+            .collect()
+    }
+
+    #[test]
+    fn with_random_objective_is_deterministic() {
+        let problem = textbook_problem();
+        let first = problem.with_random_objective(42);
+        let second = problem.with_random_objective(42);
+        let other = problem.with_random_objective(7);
+
+        assert_eq!(first.objective().objective_type(), ObjectiveType::Maximize);
+        assert_eq!(first.constraints().len(), problem.constraints().len());
+        assert!(objective_coefficients(&first)
+            .iter()
+            .all(|coefficient| (-10.0..=10.0).contains(coefficient)));
+        assert_eq!(
+            objective_coefficients(&first),
+            objective_coefficients(&second)
+        );
+        assert_ne!(
+            objective_coefficients(&first),
+            objective_coefficients(&other)
+        );
+    }
+
+    #[test]
+    fn from_canonical_matrix_builds_constraints() {
+        let c = DVector::from_vec(vec![2.0, 3.0]);
+        let a = DMatrix::from_row_slice(3, 2, &[1.0, 0.0, 0.0, 2.0, 3.0, 2.0]);
+        let b = DVector::from_vec(vec![4.0, 12.0, 18.0]);
+        let problem =
+            Problem::from_canonical_matrix(&c, &a, &b, vec!["x".to_string(), "y".to_string()])
+                .unwrap();
+
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        assert_eq!(
+            problem.objective().objective_type(),
+            ObjectiveType::Minimize
+        );
+        assert_eq!(problem.objective().expression().coefficient(&y), 3.0);
+        assert_eq!(problem.constraints().len(), 3);
+        let last = &problem.constraints()[2];
+        assert_eq!(last.relation(), Relation::LessThanOrEqual);
+        assert_eq!(last.expression().coefficient(&x), 3.0);
+        assert_eq!(last.expression().coefficient(&y), 2.0);
+        assert_eq!(last.rhs(), 18.0);
+    }
+
+    #[test]
+    fn from_canonical_matrix_dimension_mismatch() {
+        let names = || vec!["x".to_string(), "y".to_string()];
+        let c = DVector::from_vec(vec![2.0, 3.0]);
+        let a = DMatrix::from_row_slice(2, 2, &[1.0, 0.0, 0.0, 1.0]);
+        let b = DVector::from_vec(vec![4.0, 12.0]);
+
+        assert_eq!(
+            Problem::from_canonical_matrix(&DVector::from_vec(vec![1.0]), &a, &b, names()).err(),
+            Some(ProblemError::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            Problem::from_canonical_matrix(&c, &DMatrix::zeros(2, 3), &b, names()).err(),
+            Some(ProblemError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            Problem::from_canonical_matrix(&c, &a, &DVector::from_vec(vec![4.0]), names()).err(),
+            Some(ProblemError::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn has_feasible_origin_of_textbook_problem() {
+        assert!(textbook_problem().has_feasible_origin());
+    }
+
+    #[test]
+    fn has_feasible_origin_with_positive_lower_bound() {
+        let x = Variable::new("x");
+        let problem = Problem::new(
+            Objective::minimize((x.clone() * 1.0).into()),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::LessThanOrEqual, 4.0),
+                Constraint::new((x * 1.0).into(), Relation::GreaterThanOrEqual, 1.0),
+            ],
+        );
+        assert!(!problem.has_feasible_origin());
+    }
+
+    #[test]
+    fn to_ampl_dat_string_of_textbook_problem() {
+        let expected = "\
+set VARS := x y ;
+set CONS := c0 c1 c2 ;
+
+param c :=
+ x 3
+ y 5
+;
+
+param b :=
+ c0 4
+ c1 12
+ c2 18
+;
+
+param A : x y :=
+ c0 1 0
+ c1 0 2
+ c2 3 2
+;
+";
+        assert_eq!(textbook_problem().to_ampl_dat_string(), expected);
+    }
+
+    #[test]
+    fn replace_constraint_grows_and_shrinks_variables() {
+        let mut problem = textbook_problem();
+        let x = Variable::new("x");
+        let w = Variable::new("w");
+
+        let old = problem
+            .replace_constraint(
+                0,
+                Constraint::new(
+                    x.clone() * 1.0 + w.clone() * 1.0,
+                    Relation::LessThanOrEqual,
+                    5.0,
+                ),
+            )
+            .unwrap();
+        assert_eq!(old.rhs(), 4.0);
+        assert_eq!(problem.variables().len(), 3);
+        assert!(problem.variables().contains(&w));
+
+        problem.replace_constraint(0, old).unwrap();
+        assert_eq!(problem.variables().len(), 2);
+        assert!(!problem.variables().contains(&w));
+        assert_eq!(problem.constraints()[0].rhs(), 4.0);
+    }
+
+    #[test]
+    fn replace_constraint_out_of_bounds() {
+        let mut problem = textbook_problem();
+        let x = Variable::new("x");
+        assert_eq!(
+            problem
+                .replace_constraint(
+                    3,
+                    Constraint::new((x * 1.0).into(), Relation::LessThanOrEqual, 1.0)
+                )
+                .err(),
+            Some(ProblemError::ConstraintOutOfBounds(3))
+        );
+        assert_eq!(problem.constraints().len(), 3);
+    }
+
+    #[test]
+    fn debug_output_of_problem_parts() {
+        let problem = textbook_problem();
+        let debug = format!("{:?}", problem);
+        assert!(debug.starts_with("Problem"));
+        assert!(debug.contains("Maximize"));
+
+        let objective = format!("{:?}", problem.objective());
+        assert!(objective.starts_with("Objective"));
+
+        let constraint = format!("{:?}", problem.constraints()[0]);
+        assert!(constraint.starts_with("Constraint"));
+        assert!(constraint.contains("LessThanOrEqual"));
+    }
+
+    #[test]
+    fn constraint_matrix_of_textbook_problem() {
+        let (a, b, names) = textbook_problem().constraint_matrix();
+        assert_eq!((a.nrows(), a.ncols()), (3, 2));
+        assert_eq!(
+            a,
+            DMatrix::from_row_slice(3, 2, &[1.0, 0.0, 0.0, 2.0, 3.0, 2.0])
+        );
+        assert_eq!(b, DVector::from_vec(vec![4.0, 12.0, 18.0]));
+        assert_eq!(names, vec!["x".to_string(), "y".to_string()]);
+    }
+
+    #[test]
+    fn objective_vector_is_sorted_by_name() {
+        let y = Variable::new("y");
+        let x = Variable::new("x");
+        let problem = Problem::new(
+            Objective::minimize(y.clone() * 2.0 + x.clone() * -1.0),
+            vec![Constraint::new(
+                x * 1.0 + y * 1.0,
+                Relation::LessThanOrEqual,
+                1.0,
+            )],
+        );
+
+        let (c, names) = problem.objective_vector();
+        assert_eq!(c, DVector::from_vec(vec![-1.0, 2.0]));
+        assert_eq!(names, vec!["x".to_string(), "y".to_string()]);
+    }
+
+    #[test]
+    fn has_unique_solution_of_textbook_problem() {
+        assert!(textbook_problem().has_unique_solution());
+    }
+
+    #[test]
+    fn has_unique_solution_with_alternative_optima() {
+        // Every point on the edge x + y = 1 is optimal.
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let problem = Problem::new(
+            Objective::maximize(x.clone() * 1.0 + y.clone() * 1.0),
+            vec![Constraint::new(
+                x * 1.0 + y * 1.0,
+                Relation::LessThanOrEqual,
+                1.0,
+            )],
+        );
+        assert!(!problem.has_unique_solution());
+    }
+
+    #[test]
+    fn detect_infeasibility_structural_contradictory_bounds() {
+        let x = Variable::new("x");
+        let problem = Problem::new(
+            Objective::maximize((x.clone() * 1.0).into()),
+            vec![
+                Constraint::new((x.clone() * 2.0).into(), Relation::GreaterThanOrEqual, 10.0),
+                Constraint::new((x * 1.0).into(), Relation::LessThanOrEqual, 3.0),
+            ],
+        );
+        let explanation = problem.detect_infeasibility_structural().unwrap();
+        assert!(explanation.contains("Variable x"));
+    }
+
+    #[test]
+    fn detect_infeasibility_structural_negative_upper_bound() {
+        // -x >= 1 requires x <= -1, which contradicts x >= 0.
+        let x = Variable::new("x");
+        let problem = Problem::new(
+            Objective::maximize((x.clone() * 1.0).into()),
+            vec![Constraint::new(
+                (x * -1.0).into(),
+                Relation::GreaterThanOrEqual,
+                1.0,
+            )],
+        );
+        assert!(problem.detect_infeasibility_structural().is_some());
+    }
+
+    #[test]
+    fn detect_infeasibility_structural_of_feasible_problem() {
+        assert_eq!(textbook_problem().detect_infeasibility_structural(), None);
+    }
+
+    #[test]
+    fn validate_solution_at_optimum() {
+        let report = textbook_problem().validate_solution(&textbook_optimum());
+        assert_eq!(
+            report,
+            ValidationReport {
+                feasible: true,
+                non_negative: true,
+                all_finite: true,
+                violated_constraints: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn validate_solution_of_infeasible_point() {
+        let point = HashMap::from([("x".to_string(), 5.0), ("y".to_string(), -1.0)]);
+        let report = textbook_problem().validate_solution(&point);
+        assert!(!report.feasible);
+        assert!(!report.non_negative);
+        assert!(report.all_finite);
+        // Only x <= 4 is violated, since 3x + 2y = 13.
+        assert_eq!(report.violated_constraints, vec![0]);
+
+        let point = HashMap::from([("x".to_string(), 4.0), ("y".to_string(), f64::NAN)]);
+        assert!(!textbook_problem().validate_solution(&point).all_finite);
+    }
+
+    #[test]
+    fn count_binding_constraints_at_textbook_optimum() {
+        let problem = textbook_problem();
+        let solution = Solution::new(vec![("x".to_string(), 2.0), ("y".to_string(), 6.0)], 36.0);
+        // 2y <= 12 and 3x + 2y <= 18 are binding, x <= 4 has a slack of 2.
+        assert_eq!(problem.count_binding_constraints_at_optimum(&solution), 2);
+
+        let interior = Solution::new(vec![("x".to_string(), 1.0), ("y".to_string(), 1.0)], 8.0);
+        assert_eq!(problem.count_binding_constraints_at_optimum(&interior), 0);
+    }
+
+    #[test]
+    fn randomly_generated_sizes() {
+        let problem = Problem::randomly_generated(4, 3, 1);
+        assert_eq!(problem.variables().len(), 4);
+        assert_eq!(problem.constraints().len(), 3);
+        assert_eq!(
+            problem.objective().objective_type(),
+            ObjectiveType::Maximize
+        );
+        assert!(problem.has_feasible_origin());
+        assert!(problem.constraints().iter().all(|constraint| {
+            constraint.relation() == Relation::LessThanOrEqual
+                && (1.0..20.0).contains(&constraint.rhs())
+        }));
+    }
+
+    #[test]
+    fn randomly_generated_is_deterministic() {
+        let (a, b, names) = Problem::randomly_generated(3, 2, 5).constraint_matrix();
+        let (same_a, same_b, same_names) = Problem::randomly_generated(3, 2, 5).constraint_matrix();
+        assert_eq!(a, same_a);
+        assert_eq!(b, same_b);
+        assert_eq!(names, same_names);
+
+        let (other_a, _, _) = Problem::randomly_generated(3, 2, 6).constraint_matrix();
+        assert_ne!(a, other_a);
+    }
+}
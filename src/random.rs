@@ -0,0 +1,68 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+/// A small seeded pseudo-random number generator.
+///
+/// # Note
+/// This is a 64 bit linear congruential generator with the constants of Knuth's MMIX.
+/// It is fast and deterministic, which makes it suitable for generating benchmark instances,
+/// but it is not suitable for anything that requires statistical quality or security.
+pub struct Lcg {
+    /// The current state of the generator.
+    state: u64,
+}
+
+impl Lcg {
+    /// The multiplier of the generator.
+    const MULTIPLIER: u64 = 6364136223846793005;
+
+    /// The increment of the generator.
+    const INCREMENT: u64 = 1442695040888963407;
+
+    /// Create a new Lcg.
+    ///
+    /// # Arguments
+    /// * `seed` - The seed of the generator.
+    ///
+    /// # Returns
+    /// A new Lcg. Generators with the same seed produce the same sequence.
+    pub fn new(seed: u64) -> Lcg {
+        Lcg { state: seed }
+    }
+
+    /// Get the next pseudo-random integer.
+    ///
+    /// # Returns
+    /// The next pseudo-random integer.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(Lcg::MULTIPLIER)
+            .wrapping_add(Lcg::INCREMENT);
+        self.state
+    }
+
+    /// Get the next pseudo-random float in `[0, 1)`.
+    ///
+    /// # Returns
+    /// The next pseudo-random float.
+    ///
+    /// # Note
+    /// Only the upper 53 bits are used, since the lower bits of an LCG have short periods.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Get the next pseudo-random float in `[low, high)`.
+    ///
+    /// # Arguments
+    /// * `low` - The inclusive lower bound.
+    /// * `high` - The exclusive upper bound.
+    ///
+    /// # Returns
+    /// The next pseudo-random float.
+    pub fn next_in_range(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.next_f64()
+    }
+}
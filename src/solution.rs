@@ -0,0 +1,123 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use tabled::settings::Style;
+
+/// A solution of a linear program.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    /// The values of the variables, in the order of the variables of the problem.
+    values: Vec<(String, f64)>,
+
+    /// The value of the objective function at the solution.
+    objective_value: f64,
+}
+
+impl Solution {
+    /// Create a new Solution.
+    ///
+    /// # Arguments
+    /// * `values` - The names and values of the variables.
+    /// * `objective_value` - The value of the objective function at the solution.
+    ///
+    /// # Returns
+    /// A new Solution.
+    pub fn new(values: Vec<(String, f64)>, objective_value: f64) -> Solution {
+        Solution {
+            values,
+            objective_value,
+        }
+    }
+
+    /// Get the names and values of the variables.
+    ///
+    /// # Returns
+    /// The names and values of the variables.
+    pub fn values(&self) -> &Vec<(String, f64)> {
+        &self.values
+    }
+
+    /// Get the value of a variable.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the variable.
+    ///
+    /// # Returns
+    /// - `Some(value)` of the variable.
+    /// - `None` if the solution has no variable with the given name.
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|(variable, _)| variable == name)
+            .map(|&(_, value)| value)
+    }
+
+    /// Get the value of the objective function at the solution.
+    ///
+    /// # Returns
+    /// The value of the objective function at the solution.
+    pub fn objective_value(&self) -> f64 {
+        self.objective_value
+    }
+
+    /// Convert the solution into an assignment of the variables.
+    ///
+    /// # Returns
+    /// The values of the variables, keyed by variable name.
+    pub fn to_assignment(&self) -> HashMap<String, f64> {
+        self.values.iter().cloned().collect()
+    }
+
+    /// Format the solution as a table.
+    ///
+    /// # Returns
+    /// A markdown table with one row per variable and the columns `Variable` and `Value`.
+    pub fn display_as_table(&self) -> String {
+        let mut builder = tabled::builder::Builder::default();
+        builder.push_record(["Variable", "Value"]);
+        for (name, value) in &self.values {
+            builder.push_record([name.clone(), value.to_string()]);
+        }
+
+        let mut table = builder.build();
+        table.with(Style::markdown());
+        table.to_string()
+    }
+}
+
+/// Implement the Display trait for Solution.
+impl Display for Solution {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (name, value) in &self.values {
+            writeln!(f, "{} = {}", name, value)?;
+        }
+        write!(f, "objective = {}", self.objective_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_as_table_lists_variables() {
+        let solution = Solution::new(vec![("x".to_string(), 2.0), ("y".to_string(), 6.5)], 36.0);
+        let table = solution.display_as_table();
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].contains("Variable") && lines[0].contains("Value"));
+        assert!(lines[2].contains('x') && lines[2].contains('2'));
+        assert!(lines[3].contains('y') && lines[3].contains("6.5"));
+    }
+
+    #[test]
+    fn display_as_table_of_empty_solution() {
+        let table = Solution::new(Vec::new(), 0.0).display_as_table();
+        assert_eq!(table.lines().count(), 2);
+    }
+}
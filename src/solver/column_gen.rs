@@ -0,0 +1,270 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::solver::SolverOptions;
+use crate::tableau::{SimplexResult, Tableau, TableauError};
+
+/// The pricing sub-problem of column generation.
+pub trait PricingOracle {
+    /// Find a column that improves the restricted master problem.
+    ///
+    /// # Arguments
+    /// * `dual_prices` - The dual price of every constraint of the master problem.
+    ///
+    /// # Returns
+    /// - `Some((name, column))` with a column of negative reduced cost.
+    ///   The column contains the coefficients of the new variable in the original
+    ///   constraints, followed by its coefficient in the original maximization objective.
+    /// - `None` if no improving column exists.
+    fn find_entering_column(&self, dual_prices: &[f64]) -> Option<(String, DVector<f64>)>;
+}
+
+/// A solver that solves large linear programs with column generation.
+///
+/// # Note
+/// The master problem has to be a maximization problem with `<=` constraints,
+/// whose initial tableau contains a slack column for every constraint.
+/// The slack columns are used to read off the dual prices and the inverse basis,
+/// which is required to express new columns in terms of the current basis.
+pub struct ColumnGenerationSolver {
+    /// The tableau of the restricted master problem.
+    master: Tableau,
+
+    /// The index of the slack column of every constraint row.
+    slack_columns: Vec<usize>,
+
+    /// The oracle that solves the pricing sub-problem.
+    oracle: Box<dyn PricingOracle>,
+
+    /// The options of the solver.
+    options: SolverOptions,
+
+    /// The number of columns that were generated.
+    generated_columns: usize,
+}
+
+impl ColumnGenerationSolver {
+    /// Create a new ColumnGenerationSolver.
+    ///
+    /// # Arguments
+    /// * `master` - The feasible initial tableau of the restricted master problem.
+    /// * `slack_columns` - The index of the slack column of every constraint row.
+    /// * `oracle` - The oracle that solves the pricing sub-problem.
+    ///
+    /// # Returns
+    /// A new ColumnGenerationSolver.
+    pub fn new(
+        master: Tableau,
+        slack_columns: Vec<usize>,
+        oracle: Box<dyn PricingOracle>,
+    ) -> ColumnGenerationSolver {
+        ColumnGenerationSolver {
+            master,
+            slack_columns,
+            oracle,
+            options: SolverOptions::default(),
+            generated_columns: 0,
+        }
+    }
+
+    /// Set the options of the solver.
+    ///
+    /// # Arguments
+    /// * `options` - The options. `max_iterations` limits the number of generated columns.
+    pub fn set_options(&mut self, options: SolverOptions) {
+        self.options = options;
+    }
+
+    /// Get the tableau of the restricted master problem.
+    ///
+    /// # Returns
+    /// The tableau of the restricted master problem.
+    pub fn master(&self) -> &Tableau {
+        &self.master
+    }
+
+    /// Get the number of columns that were generated.
+    ///
+    /// # Returns
+    /// The number of columns that were added to the master problem.
+    pub fn generated_columns(&self) -> usize {
+        self.generated_columns
+    }
+
+    /// Get the dual prices of the current master tableau.
+    ///
+    /// # Returns
+    /// The objective row entries of the slack columns.
+    pub fn dual_prices(&self) -> Vec<f64> {
+        self.slack_columns
+            .iter()
+            .map(|&col| self.master.obj_row_entry(col).unwrap_or(0.0))
+            .collect()
+    }
+
+    /// Solve the problem by alternating between the master and the pricing problem.
+    ///
+    /// # Returns
+    /// - `Ok(SimplexResult::Optimal)` if the oracle finds no improving column.
+    /// - `Ok(SimplexResult::Unbounded)` if the master problem is unbounded.
+    /// - `Ok(SimplexResult::Interrupted)` if the column limit was reached.
+    /// - `Err(TableauError::DimensionMismatch)` if the oracle returns a column
+    ///   whose length is not the number of constraints plus one.
+    pub fn solve(&mut self) -> Result<SimplexResult, TableauError> {
+        loop {
+            let result = self.master.solve();
+            if result != SimplexResult::Optimal {
+                return Ok(result);
+            }
+
+            if self.generated_columns >= self.options.max_iterations {
+                return Ok(SimplexResult::Interrupted(self.generated_columns as u64));
+            }
+
+            let dual_prices = self.dual_prices();
+            let Some((name, column)) = self.oracle.find_entering_column(&dual_prices) else {
+                return Ok(SimplexResult::Optimal);
+            };
+
+            let m = self.slack_columns.len();
+            if column.len() != m + 1 {
+                return Err(TableauError::DimensionMismatch {
+                    expected: m + 1,
+                    actual: column.len(),
+                });
+            }
+            let coefficients = column.rows(0, m);
+            let cost = column[m];
+
+            // A column that does not improve the master problem would be generated forever.
+            let reduced_cost = DVector::from_column_slice(&dual_prices).dot(&coefficients) - cost;
+            if reduced_cost >= -self.master.tolerance() {
+                return Ok(SimplexResult::Optimal);
+            }
+
+            // Express the column in terms of the current basis using the inverse basis,
+            // which is stored in the slack columns.
+            let inverse_basis = DMatrix::from_fn(m, m, |r, c| {
+                self.master.get_matrix()[(r, self.slack_columns[c])]
+            });
+            let mut entries = (inverse_basis * coefficients).insert_row(m, 0.0);
+            entries[m] = reduced_cost;
+
+            self.master.add_column(name, entries)?;
+            self.generated_columns += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The items of a fractional knapsack with capacity 10, as `(name, weight, value)`.
+    const ITEMS: [(&str, f64, f64); 4] = [
+        ("a", 5.0, 10.0),
+        ("b", 4.0, 40.0),
+        ("c", 6.0, 30.0),
+        ("d", 3.0, 50.0),
+    ];
+
+    /// An oracle that prices the items of the knapsack.
+    ///
+    /// # Note
+    /// The master problem has the capacity row followed by the row `x_i <= 1` of every item.
+    struct KnapsackOracle;
+
+    impl PricingOracle for KnapsackOracle {
+        fn find_entering_column(&self, dual_prices: &[f64]) -> Option<(String, DVector<f64>)> {
+            ITEMS
+                .iter()
+                .enumerate()
+                .map(|(i, &(name, weight, value))| {
+                    let reduced_cost = value - dual_prices[0] * weight - dual_prices[i + 1];
+                    (reduced_cost, i, name, weight, value)
+                })
+                .filter(|&(reduced_cost, ..)| reduced_cost > 1e-9)
+                .max_by(|a, b| a.0.total_cmp(&b.0))
+                .map(|(_, i, name, weight, value)| {
+                    let mut column = DVector::zeros(ITEMS.len() + 2);
+                    column[0] = weight;
+                    column[i + 1] = 1.0;
+                    column[ITEMS.len() + 1] = value;
+                    (name.to_string(), column)
+                })
+        }
+    }
+
+    /// An oracle that returns a column without the objective coefficient.
+    struct ShortColumnOracle;
+
+    impl PricingOracle for ShortColumnOracle {
+        fn find_entering_column(&self, dual_prices: &[f64]) -> Option<(String, DVector<f64>)> {
+            Some((
+                "short".to_string(),
+                DVector::from_element(dual_prices.len(), 1.0),
+            ))
+        }
+    }
+
+    /// The master problem of the knapsack without any item columns.
+    fn knapsack_master() -> (Tableau, Vec<usize>) {
+        let m = ITEMS.len() + 1;
+        let mut matrix = DMatrix::zeros(m + 1, m + 1);
+        for r in 0..m {
+            matrix[(r, r)] = 1.0;
+            matrix[(r, m)] = 1.0;
+        }
+        matrix[(0, m)] = 10.0;
+
+        let mut row_names: Vec<String> = (0..m).map(|r| format!("s_{}", r)).collect();
+        let mut column_names = row_names.clone();
+        row_names.push("z".to_string());
+        column_names.push("rhs".to_string());
+
+        let mut master = Tableau::new(matrix, row_names, column_names);
+        master.set_basis((0..m).collect());
+        (master, (0..m).collect())
+    }
+
+    #[test]
+    fn solve_knapsack() {
+        let (master, slack_columns) = knapsack_master();
+        let mut solver =
+            ColumnGenerationSolver::new(master, slack_columns, Box::new(KnapsackOracle));
+
+        assert_eq!(solver.solve(), Ok(SimplexResult::Optimal));
+        assert!((solver.master().objective_value() - 105.0).abs() < 1e-9);
+        assert!(solver.generated_columns() >= 3);
+        assert!(solver.master().col_index_by_name("a").is_none());
+    }
+
+    #[test]
+    fn solve_rejects_columns_of_wrong_length() {
+        let (master, slack_columns) = knapsack_master();
+        let mut solver =
+            ColumnGenerationSolver::new(master, slack_columns, Box::new(ShortColumnOracle));
+
+        assert_eq!(
+            solver.solve(),
+            Err(TableauError::DimensionMismatch {
+                expected: ITEMS.len() + 2,
+                actual: ITEMS.len() + 1,
+            })
+        );
+        assert_eq!(solver.generated_columns(), 0);
+    }
+
+    #[test]
+    fn solve_stops_at_column_limit() {
+        let (master, slack_columns) = knapsack_master();
+        let mut solver =
+            ColumnGenerationSolver::new(master, slack_columns, Box::new(KnapsackOracle));
+        solver.set_options(SolverOptions { max_iterations: 1 });
+
+        assert_eq!(solver.solve(), Ok(SimplexResult::Interrupted(1)));
+    }
+}
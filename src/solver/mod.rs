@@ -0,0 +1,529 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+pub mod column_gen;
+
+use std::time::{Duration, Instant};
+
+use crate::solution::Solution;
+use crate::tableau::{RangingResult, SimplexResult, Tableau, TableauError};
+
+/// The options of a simplex solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolverOptions {
+    /// The maximum number of pivots per call to `solve`.
+    pub max_iterations: usize,
+}
+
+impl Default for SolverOptions {
+    fn default() -> SolverOptions {
+        SolverOptions {
+            max_iterations: 10_000,
+        }
+    }
+}
+
+/// The status of a simplex solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverStatus {
+    /// The tableau was not solved yet.
+    NotSolved,
+
+    /// The tableau is optimal.
+    Optimal,
+
+    /// The problem is unbounded.
+    Unbounded,
+
+    /// The iteration limit was reached before the tableau became optimal.
+    IterationLimit,
+}
+
+/// The sensitivity analysis of an optimal tableau.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensitivityReport {
+    /// The objective coefficient and rhs ranges of every column, keyed by column name,
+    /// in the order of the columns. The rhs ranges are only meaningful for slack columns.
+    pub ranges: Vec<(String, RangingResult)>,
+}
+
+/// The detailed result of solving a tableau.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveReport {
+    /// The status of the solver after solving.
+    pub status: SolverStatus,
+
+    /// The optimal objective value, if the tableau was solved to optimality.
+    pub objective_value: Option<f64>,
+
+    /// The optimal solution, if the tableau was solved to optimality.
+    pub solution: Option<Solution>,
+
+    /// The number of pivots that were performed.
+    pub iterations: u64,
+
+    /// The wall-clock time solving took.
+    pub time_elapsed: Duration,
+
+    /// The sensitivity analysis, if the tableau was solved to optimality.
+    pub sensitivity: Option<SensitivityReport>,
+}
+
+/// A solver that solves a tableau with the simplex method.
+pub struct SimplexSolver {
+    /// The tableau that is solved.
+    tableau: Tableau,
+
+    /// The options of the solver.
+    options: SolverOptions,
+
+    /// The status of the solver.
+    status: SolverStatus,
+}
+
+impl SimplexSolver {
+    /// Create a new SimplexSolver with the default options.
+    ///
+    /// # Arguments
+    /// * `tableau` - The tableau to solve, which has to be feasible.
+    ///
+    /// # Returns
+    /// A new SimplexSolver.
+    pub fn new(tableau: Tableau) -> SimplexSolver {
+        SimplexSolver::with_options(tableau, SolverOptions::default())
+    }
+
+    /// Create a new SimplexSolver.
+    ///
+    /// # Arguments
+    /// * `tableau` - The tableau to solve, which has to be feasible.
+    /// * `options` - The options of the solver.
+    ///
+    /// # Returns
+    /// A new SimplexSolver.
+    pub fn with_options(tableau: Tableau, options: SolverOptions) -> SimplexSolver {
+        SimplexSolver {
+            tableau,
+            options,
+            status: SolverStatus::NotSolved,
+        }
+    }
+
+    /// Get the tableau of the solver.
+    ///
+    /// # Returns
+    /// The tableau of the solver.
+    pub fn tableau(&self) -> &Tableau {
+        &self.tableau
+    }
+
+    /// Get the options of the solver.
+    ///
+    /// # Returns
+    /// The options of the solver.
+    pub fn options(&self) -> &SolverOptions {
+        &self.options
+    }
+
+    /// Get the status of the solver.
+    ///
+    /// # Returns
+    /// The status of the solver.
+    pub fn status(&self) -> SolverStatus {
+        self.status
+    }
+
+    /// Replace the tableau of the solver.
+    ///
+    /// # Arguments
+    /// * `tableau` - The new tableau to solve, which has to be feasible.
+    ///
+    /// # Note
+    /// The status is reset, but the options are kept.
+    pub fn set_problem_from_tableau(&mut self, tableau: Tableau) {
+        self.tableau = tableau;
+        self.status = SolverStatus::NotSolved;
+    }
+
+    /// Reset the status and the pivot history of the solver.
+    ///
+    /// # Note
+    /// The tableau itself is kept in its current state.
+    pub fn reset(&mut self) {
+        self.tableau.clear_history();
+        self.status = SolverStatus::NotSolved;
+    }
+
+    /// Warm start the solver from a given basis.
+    ///
+    /// # Arguments
+    /// * `basis` - The index of the basic column of each constraint row,
+    ///   e.g. the optimal basis of a previous solve.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the tableau was recomputed for the basis.
+    /// - `Err(TableauError::DimensionMismatch)` if the basis does not have one column per constraint row.
+    /// - `Err(TableauError::ColumnOutOfBounds)` if a basic column is out of bounds.
+    /// - `Err(TableauError::SingularBasis)` if the basic columns are linearly dependent.
+    ///
+    /// # Note
+    /// The basis has to consist of linearly independent columns, but it does not have to be
+    /// feasible. The tableau is recomputed from its original matrix, see `Tableau::refactorize`.
+    /// If the resulting tableau is not feasible, it has to be made feasible before calling `solve`.
+    /// Starting from a nearly optimal basis usually saves many pivots.
+    /// If an error is returned, the tableau and the status of the solver are left unchanged.
+    pub fn warm_start_from(&mut self, basis: Vec<usize>) -> Result<(), TableauError> {
+        self.tableau.refactorize_with_basis(basis)?;
+        self.status = SolverStatus::NotSolved;
+        Ok(())
+    }
+
+    /// Solve the tableau with the simplex method.
+    ///
+    /// # Returns
+    /// - `SimplexResult::Optimal` if the tableau is optimal.
+    /// - `SimplexResult::Unbounded` if the problem is unbounded.
+    /// - `SimplexResult::Interrupted` if the iteration limit was reached.
+    ///
+    /// # Note
+    /// With the `logging` feature, the objective value is logged after every pivot.
+    pub fn solve(&mut self) -> SimplexResult {
+        #[cfg(not(feature = "logging"))]
+        let result = self.tableau.solve_for_n_pivots(self.options.max_iterations);
+        #[cfg(feature = "logging")]
+        let result = self.solve_with_logging();
+
+        self.status = match result {
+            SimplexResult::Optimal => SolverStatus::Optimal,
+            SimplexResult::Unbounded => SolverStatus::Unbounded,
+            SimplexResult::Interrupted(_) => SolverStatus::IterationLimit,
+        };
+        result
+    }
+
+    /// Solve the tableau one pivot at a time and log the progress.
+    ///
+    /// # Returns
+    /// The same result as `Tableau::solve_for_n_pivots` with the iteration limit.
+    #[cfg(feature = "logging")]
+    fn solve_with_logging(&mut self) -> SimplexResult {
+        let mut result = self.tableau.solve_for_n_pivots(0);
+        let mut iterations: u64 = 0;
+        while let SimplexResult::Interrupted(_) = result {
+            if iterations == self.options.max_iterations as u64 {
+                log::debug!("Iteration limit of {} reached", iterations);
+                return SimplexResult::Interrupted(iterations);
+            }
+
+            // An unbounded tableau is detected before pivoting.
+            result = self.tableau.solve_for_n_pivots(1);
+            if result != SimplexResult::Unbounded {
+                iterations += 1;
+                log::debug!(
+                    "Iteration {}: objective value {}",
+                    iterations,
+                    self.tableau.objective_value()
+                );
+            }
+        }
+
+        log::debug!("Finished after {} iterations: {:?}", iterations, result);
+        result
+    }
+
+    /// Solve the tableau with the simplex method and report the details.
+    ///
+    /// # Returns
+    /// The status, the number of pivots and the elapsed time of the solve.
+    /// The objective value, the solution and the sensitivity analysis are only
+    /// populated if the tableau was solved to optimality.
+    ///
+    /// # Note
+    /// The solution contains the value of every column except the rhs column,
+    /// including slack variables. The objective value is the one of the tableau,
+    /// i.e. in the sense of the maximization problem represented by the tableau.
+    pub fn solve_and_report(&mut self) -> SolveReport {
+        let pivots_before = self.tableau.total_pivot_count();
+        let start = Instant::now();
+        let result = self.solve();
+        let time_elapsed = start.elapsed();
+        let iterations = (self.tableau.total_pivot_count() - pivots_before) as u64;
+
+        let mut report = SolveReport {
+            status: self.status,
+            objective_value: None,
+            solution: None,
+            iterations,
+            time_elapsed,
+            sensitivity: None,
+        };
+        if result != SimplexResult::Optimal {
+            return report;
+        }
+
+        let objective_value = self.tableau.objective_value();
+        let columns = &self.tableau.column_names()[..self.tableau.cols() - 1];
+        let values = columns
+            .iter()
+            .enumerate()
+            .map(|(col, name)| (name.clone(), self.tableau.column_value(col)))
+            .collect();
+        let ranges = columns
+            .iter()
+            .filter_map(|name| {
+                let ranging = self.tableau.sensitivity_ranging_for_variable(name)?;
+                Some((name.clone(), ranging))
+            })
+            .collect();
+
+        report.objective_value = Some(objective_value);
+        report.solution = Some(Solution::new(values, objective_value));
+        report.sensitivity = Some(SensitivityReport { ranges });
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraint::{Constraint, Relation};
+    use crate::problem::{Objective, Problem};
+    use crate::tableau_builder::SimplexTableauBuilder;
+    use crate::variable::Variable;
+
+    /// The textbook problem `max 3x + 5y s.t. x <= 4, 2y <= 12, 3x + 2y <= 18`.
+    fn textbook_tableau() -> Tableau {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let problem = Problem::new(
+            Objective::maximize(x.clone() * 3.0 + y.clone() * 5.0),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::LessThanOrEqual, 4.0),
+                Constraint::new((y.clone() * 2.0).into(), Relation::LessThanOrEqual, 12.0),
+                Constraint::new(x * 3.0 + y * 2.0, Relation::LessThanOrEqual, 18.0),
+            ],
+        );
+        SimplexTableauBuilder::from_problem(&problem).build().0
+    }
+
+    #[test]
+    fn solve_two_problems_sequentially() {
+        let mut solver = SimplexSolver::new(textbook_tableau());
+        assert_eq!(solver.solve(), SimplexResult::Optimal);
+        assert_eq!(solver.status(), SolverStatus::Optimal);
+        assert!((solver.tableau().objective_value() - 36.0).abs() < 1e-9);
+
+        // max x s.t. x <= 2
+        let x = Variable::new("x");
+        let problem = Problem::new(
+            Objective::maximize((x.clone() * 1.0).into()),
+            vec![Constraint::new(
+                (x * 1.0).into(),
+                Relation::LessThanOrEqual,
+                2.0,
+            )],
+        );
+        solver.set_problem_from_tableau(SimplexTableauBuilder::from_problem(&problem).build().0);
+        assert_eq!(solver.status(), SolverStatus::NotSolved);
+        assert_eq!(solver.solve(), SimplexResult::Optimal);
+        assert!((solver.tableau().objective_value() - 2.0).abs() < 1e-9);
+        assert_eq!(solver.tableau().total_pivot_count(), 1);
+    }
+
+    #[test]
+    fn warm_start_saves_iterations() {
+        let mut cold = SimplexSolver::new(textbook_tableau());
+        assert_eq!(cold.solve(), SimplexResult::Optimal);
+        let cold_iterations = cold.tableau().total_pivot_count();
+
+        let mut warm = SimplexSolver::new(textbook_tableau());
+        warm.warm_start_from(cold.tableau().basis().unwrap().clone())
+            .unwrap();
+        assert_eq!(warm.solve(), SimplexResult::Optimal);
+
+        assert!(warm.tableau().total_pivot_count() < cold_iterations);
+        assert!((warm.tableau().objective_value() - 36.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn warm_start_rejects_invalid_basis() {
+        let mut solver = SimplexSolver::new(textbook_tableau());
+        assert_eq!(
+            solver.warm_start_from(vec![0, 1]),
+            Err(TableauError::DimensionMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            solver.warm_start_from(vec![0, 1, 7]),
+            Err(TableauError::ColumnOutOfBounds(7))
+        );
+    }
+
+    #[test]
+    fn warm_start_failure_keeps_state() {
+        let mut tableau = textbook_tableau();
+        tableau.compute_basis_inverse().unwrap();
+        let matrix = tableau.get_matrix().clone();
+        let basis = tableau.basis().cloned();
+
+        let mut solver = SimplexSolver::new(tableau);
+        assert_eq!(
+            solver.warm_start_from(vec![0, 0, 1]),
+            Err(TableauError::SingularBasis)
+        );
+        assert_eq!(solver.tableau().get_matrix(), &matrix);
+        assert_eq!(solver.tableau().basis().cloned(), basis);
+        assert!(solver.tableau().basis_inverse().is_some());
+    }
+
+    #[test]
+    fn warm_start_failure_keeps_untracked_basis() {
+        let tableau = textbook_tableau();
+        let untracked = Tableau::new(
+            tableau.get_matrix().clone(),
+            tableau.row_names().clone(),
+            tableau.column_names().clone(),
+        );
+
+        let mut solver = SimplexSolver::new(untracked);
+        assert_eq!(
+            solver.warm_start_from(vec![0, 0, 1]),
+            Err(TableauError::SingularBasis)
+        );
+        assert!(solver.tableau().basis().is_none());
+    }
+
+    #[test]
+    fn solve_and_report_populates_optimal_fields() {
+        let mut solver = SimplexSolver::new(textbook_tableau());
+        let report = solver.solve_and_report();
+
+        assert_eq!(report.status, SolverStatus::Optimal);
+        assert_eq!(report.iterations, 2);
+        assert!((report.objective_value.unwrap() - 36.0).abs() < 1e-9);
+        let solution = report.solution.unwrap();
+        assert!((solution.value("x").unwrap() - 2.0).abs() < 1e-9);
+        assert!((solution.value("y").unwrap() - 6.0).abs() < 1e-9);
+        assert!((solution.value("s_0").unwrap() - 2.0).abs() < 1e-9);
+        let sensitivity = report.sensitivity.unwrap();
+        assert_eq!(sensitivity.ranges.len(), 5);
+        assert_eq!(sensitivity.ranges[0].0, "x");
+    }
+
+    #[test]
+    fn solve_and_report_at_iteration_limit() {
+        let options = SolverOptions { max_iterations: 1 };
+        let mut solver = SimplexSolver::with_options(textbook_tableau(), options);
+        let report = solver.solve_and_report();
+
+        assert_eq!(report.status, SolverStatus::IterationLimit);
+        assert_eq!(report.iterations, 1);
+        assert_eq!(report.objective_value, None);
+        assert_eq!(report.solution, None);
+        assert_eq!(report.sensitivity, None);
+    }
+}
+
+#[cfg(all(test, feature = "logging"))]
+mod logging_tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::constraint::{Constraint, Relation};
+    use crate::problem::{Objective, Problem};
+    use crate::tableau_builder::SimplexTableauBuilder;
+    use crate::variable::Variable;
+
+    thread_local! {
+        /// The records logged by the current thread, so tests running in parallel do not interfere.
+        static RECORDS: RefCell<Vec<(log::Level, String)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// A logger that captures the records of each thread.
+    struct CapturingLogger;
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            RECORDS.with(|records| {
+                records
+                    .borrow_mut()
+                    .push((record.level(), record.args().to_string()))
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CapturingLogger = CapturingLogger;
+
+    /// Install the capturing logger and discard the records of the current thread.
+    fn setup() {
+        // Another test may have installed the logger already.
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Debug);
+        RECORDS.with(|records| records.borrow_mut().clear());
+    }
+
+    /// Get the messages logged by the current thread since `setup`.
+    fn captured() -> Vec<(log::Level, String)> {
+        RECORDS.with(|records| records.borrow().clone())
+    }
+
+    /// The textbook problem `max 3x + 5y s.t. x <= 4, 2y <= 12, 3x + 2y <= 18`.
+    fn textbook_tableau() -> Tableau {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let problem = Problem::new(
+            Objective::maximize(x.clone() * 3.0 + y.clone() * 5.0),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::LessThanOrEqual, 4.0),
+                Constraint::new((y.clone() * 2.0).into(), Relation::LessThanOrEqual, 12.0),
+                Constraint::new(x * 3.0 + y * 2.0, Relation::LessThanOrEqual, 18.0),
+            ],
+        );
+        SimplexTableauBuilder::from_problem(&problem).build().0
+    }
+
+    #[test]
+    fn solve_logs_every_iteration() {
+        setup();
+        let mut solver = SimplexSolver::new(textbook_tableau());
+        assert_eq!(solver.solve(), SimplexResult::Optimal);
+
+        let logs = captured();
+        let messages: Vec<&str> = logs.iter().map(|(_, message)| message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Iteration 1: objective value 30",
+                "Iteration 2: objective value 36",
+                "Finished after 2 iterations: Optimal",
+            ]
+        );
+        assert!(logs.iter().all(|(level, _)| *level == log::Level::Debug));
+    }
+
+    #[test]
+    fn solve_logs_iteration_limit() {
+        setup();
+        let options = SolverOptions { max_iterations: 1 };
+        let mut solver = SimplexSolver::with_options(textbook_tableau(), options);
+        assert_eq!(solver.solve(), SimplexResult::Interrupted(1));
+
+        let messages: Vec<String> = captured().into_iter().map(|(_, message)| message).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Iteration 1: objective value 30".to_string(),
+                "Iteration limit of 1 reached".to_string(),
+            ]
+        );
+    }
+}
@@ -2,21 +2,223 @@
 
 #![allow(dead_code)]
 
+use std::collections::HashMap;
 use std::fmt::{Display};
+use std::time::{Duration, Instant};
 
-use nalgebra::{DMatrix, Dyn, MatrixView, U1};
+use nalgebra::{DMatrix, DVector, Dyn, MatrixView, U1};
 use tabled::settings::Style;
 
+use crate::expression::{LinearExpression, LinearTerm};
+use crate::matrix::Matrix2D;
+use crate::problem::Problem;
+use crate::tableau_builder::SimplexTableauBuilder;
+use crate::variable::Variable;
+
+/// The default tolerance used when comparing values of the tableau against zero.
+const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+/// An error that can occur when operating on a tableau.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TableauError {
+    /// The given row index is out of the bounds of the tableau.
+    RowOutOfBounds(usize),
+
+    /// The given column index is out of the bounds of the tableau.
+    ColumnOutOfBounds(usize),
+
+    /// The column has no positive entry in the constraint rows, so the problem is unbounded.
+    Unbounded,
+
+    /// The identity columns of the tableau do not form a complete basis.
+    NoBasisFound,
+
+    /// The length of a vector does not match the dimension of the tableau.
+    DimensionMismatch {
+        /// The expected length.
+        expected: usize,
+
+        /// The actual length.
+        actual: usize,
+    },
+
+    /// The columns of the basis are linearly dependent.
+    SingularBasis,
+}
+
+/// An error that can occur when building a tableau.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableauBuildError {
+    /// The number of row names did not match the number of rows of the matrix.
+    RowNameCountMismatch {
+        /// The number of rows of the matrix.
+        expected: usize,
+
+        /// The number of row names.
+        actual: usize,
+    },
+
+    /// The number of column names did not match the number of columns of the matrix.
+    ColumnNameCountMismatch {
+        /// The number of columns of the matrix.
+        expected: usize,
+
+        /// The number of column names.
+        actual: usize,
+    },
+
+    /// No value was given for the entry of a row and a column.
+    MissingEntry {
+        /// The name of the row.
+        row: String,
+
+        /// The name of the column.
+        column: String,
+    },
+
+    /// Neither the constraints nor the objective reference any variable.
+    NoVariables,
+}
+
+/// An error that describes an inconsistency of a tableau.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum ConsistencyError {
+    /// The number of row names does not match the number of rows of the matrix.
+    RowNameCountMismatch {
+        /// The number of rows of the matrix.
+        expected: usize,
+
+        /// The number of row names.
+        actual: usize,
+    },
+
+    /// The number of column names does not match the number of columns of the matrix.
+    ColumnNameCountMismatch {
+        /// The number of columns of the matrix.
+        expected: usize,
+
+        /// The number of column names.
+        actual: usize,
+    },
+
+    /// The length of the basis does not match the number of constraint rows.
+    BasisLengthMismatch {
+        /// The number of constraint rows.
+        expected: usize,
+
+        /// The length of the basis.
+        actual: usize,
+    },
+}
+
+/// The result of solving a tableau with the simplex method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimplexResult {
+    /// The tableau is optimal.
+    Optimal,
+
+    /// The problem is unbounded.
+    Unbounded,
+
+    /// The pivot limit was reached before the tableau became optimal.
+    /// Contains the number of pivots that were performed.
+    Interrupted(u64),
+}
+
+/// The outcome of a single pivot chosen by a pivot strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PivotOutcome {
+    /// The pivot was performed.
+    Pivoted(PivotStep),
+
+    /// The strategy did not choose a pivot, e.g. because the tableau is optimal.
+    NoPivot,
+
+    /// The chosen pivot is not in the constraint part of the tableau or its element is zero.
+    /// The tableau is left unchanged.
+    InvalidPivot(PivotStep),
+}
+
+/// The ranges within which a variable's data may change without changing the optimal basis.
+///
+/// # Note
+/// All values are allowable changes relative to the current values,
+/// in the sense of the maximization problem represented by the tableau.
+/// Unbounded changes are represented by `f64::INFINITY`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RangingResult {
+    /// The allowable decrease of the objective coefficient of the variable.
+    pub objective_decrease: f64,
+
+    /// The allowable increase of the objective coefficient of the variable.
+    pub objective_increase: f64,
+
+    /// The allowable decrease of the rhs of the constraint whose slack variable is the variable.
+    pub rhs_decrease: f64,
+
+    /// The allowable increase of the rhs of the constraint whose slack variable is the variable.
+    pub rhs_increase: f64,
+}
+
+/// A single pivot operation that was performed on a tableau.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PivotStep {
+    /// The index of the pivot row.
+    pub row: usize,
+
+    /// The index of the pivot column.
+    pub column: usize,
+}
+
+/// A timed pivot operation that was performed on a tableau.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PivotLog {
+    /// The pivot operation.
+    pub step: PivotStep,
+
+    /// The wall-clock time the pivot operation took.
+    pub duration: Duration,
+}
+
 /// A tableau that represents a linear program.
 pub struct Tableau {
     /// The matrix that represents the tableau.
     matrix: DMatrix<f64>,
+
+    /// The matrix of the tableau at construction, which is used for refactorization.
+    original: DMatrix<f64>,
     
     /// The names of the rows of the tableau.
     row_names: Vec<String>,
     
     /// The names of the columns of the tableau.
     column_names: Vec<String>,
+
+    /// The index of the basic column of each constraint row, if the basis is tracked.
+    basis: Option<Vec<usize>>,
+
+    /// The inverse of the original basis columns, if it was computed.
+    basis_inverse: Option<DMatrix<f64>>,
+
+    /// The perturbation of the rhs column, transformed by all pivots since it was added,
+    /// if the rhs is perturbed.
+    perturbation: Option<DVector<f64>>,
+
+    /// The pivot operations that were performed on the tableau.
+    history: Vec<PivotStep>,
+
+    /// The number of pivot operations that were performed on the tableau.
+    pivot_count: usize,
+
+    /// The timed pivot operations, if timing is enabled.
+    pivot_log: Vec<PivotLog>,
+
+    /// Whether the pivot operations are timed.
+    timing_enabled: bool,
+
+    /// The tolerance used when comparing values of the tableau against zero.
+    tolerance: f64,
 }
 
 impl Tableau {
@@ -42,10 +244,210 @@ impl Tableau {
         
         // Create the tableau.
         Tableau {
+            original: matrix.clone(),
             matrix,
             row_names,
             column_names,
+            basis: None,
+            basis_inverse: None,
+            perturbation: None,
+            history: Vec::new(),
+            pivot_count: 0,
+            pivot_log: Vec::new(),
+            timing_enabled: false,
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Create a new Tableau from a Matrix2D.
+    ///
+    /// # Arguments
+    /// * `matrix` - The matrix that represents the tableau.
+    /// * `row_names` - The names of the rows of the tableau.
+    /// * `column_names` - The names of the columns of the tableau.
+    ///
+    /// # Returns
+    /// - `Ok(tableau)` if the names match the dimensions of the matrix.
+    /// - `Err(TableauBuildError)` otherwise.
+    pub fn from_matrix2d(
+        matrix: Matrix2D<f64>,
+        row_names: Vec<String>,
+        column_names: Vec<String>,
+    ) -> Result<Tableau, TableauBuildError> {
+        if matrix.rows() != row_names.len() {
+            return Err(TableauBuildError::RowNameCountMismatch {
+                expected: matrix.rows(),
+                actual: row_names.len(),
+            });
         }
+
+        if matrix.columns() != column_names.len() {
+            return Err(TableauBuildError::ColumnNameCountMismatch {
+                expected: matrix.columns(),
+                actual: column_names.len(),
+            });
+        }
+
+        Ok(Tableau::new(matrix.to_dmatrix(), row_names, column_names))
+    }
+
+    /// Create a new Tableau from a dictionary.
+    ///
+    /// # Arguments
+    /// * `dict` - The entries of the tableau keyed by `(row_name, column_name)`.
+    ///
+    /// # Returns
+    /// - `Ok(tableau)` if there is an entry for every combination of row and column names.
+    /// - `Err(TableauBuildError::MissingEntry)` otherwise.
+    ///
+    /// # Note
+    /// A dictionary does not preserve the order of the rows and columns,
+    /// therefore the rows and columns of the tableau are sorted by their names.
+    /// The objective row `z` and the rhs column `rhs` are moved to the last position,
+    /// as the tableau expects them there. Use `from_dict_ordered` to restore a tableau
+    /// with a different objective row or rhs column name.
+    pub fn from_dict(dict: HashMap<(String, String), f64>) -> Result<Tableau, TableauBuildError> {
+        // Collect the distinct row and column names in sorted order.
+        let mut row_names: Vec<String> = dict.keys().map(|(row, _)| row.clone()).collect();
+        row_names.sort();
+        row_names.dedup();
+        let mut column_names: Vec<String> = dict.keys().map(|(_, column)| column.clone()).collect();
+        column_names.sort();
+        column_names.dedup();
+
+        // The objective row and the rhs column always come last.
+        if let Some(index) = row_names.iter().position(|row| row == "z") {
+            let objective = row_names.remove(index);
+            row_names.push(objective);
+        }
+        if let Some(index) = column_names.iter().position(|column| column == "rhs") {
+            let rhs = column_names.remove(index);
+            column_names.push(rhs);
+        }
+
+        Tableau::from_dict_ordered(dict, row_names, column_names)
+    }
+
+    /// Create a new Tableau from a dictionary with the given order of rows and columns.
+    ///
+    /// # Arguments
+    /// * `dict` - The entries of the tableau keyed by `(row_name, column_name)`.
+    /// * `row_names` - The names of the rows, the objective row last.
+    /// * `column_names` - The names of the columns, the rhs column last.
+    ///
+    /// # Returns
+    /// - `Ok(tableau)` if there is an entry for every combination of row and column names.
+    /// - `Err(TableauBuildError::MissingEntry)` otherwise.
+    ///
+    /// # Note
+    /// Entries of the dictionary whose names are not listed are ignored.
+    pub fn from_dict_ordered(
+        dict: HashMap<(String, String), f64>,
+        row_names: Vec<String>,
+        column_names: Vec<String>,
+    ) -> Result<Tableau, TableauBuildError> {
+        let mut matrix = DMatrix::zeros(row_names.len(), column_names.len());
+        for (r, row) in row_names.iter().enumerate() {
+            for (c, column) in column_names.iter().enumerate() {
+                let key = (row.clone(), column.clone());
+                matrix[(r, c)] = *dict.get(&key).ok_or(TableauBuildError::MissingEntry {
+                    row: row.clone(),
+                    column: column.clone(),
+                })?;
+            }
+        }
+
+        Ok(Tableau::new(matrix, row_names, column_names))
+    }
+
+    /// Create a new Tableau from equality constraints and an objective row.
+    ///
+    /// # Arguments
+    /// * `equations` - The equality constraints as `(expression, rhs)` pairs.
+    /// * `objective` - The entries of the objective row, e.g. `-c` for a maximization.
+    ///
+    /// # Returns
+    /// - `Ok(tableau)` with one row per equation, the objective row `z`,
+    ///   one column per variable and the rhs column.
+    /// - `Err(TableauBuildError::NoVariables)` if no expression references a variable.
+    ///
+    /// # Note
+    /// The columns are ordered by the first occurrence of the variables in the equations
+    /// and then in the objective. The rows are named `c0`, `c1`, ... as in the LP file format.
+    /// If the equations contain an identity basis, it is tracked by the tableau.
+    pub fn from_equations(
+        equations: &[(LinearExpression, f64)],
+        objective: LinearExpression,
+    ) -> Result<Tableau, TableauBuildError> {
+        let mut variables: Vec<Variable> = Vec::new();
+        for expression in equations
+            .iter()
+            .map(|(expression, _)| expression)
+            .chain([&objective])
+        {
+            for variable in expression.variables() {
+                if !variables.contains(&variable) {
+                    variables.push(variable);
+                }
+            }
+        }
+        if variables.is_empty() {
+            return Err(TableauBuildError::NoVariables);
+        }
+
+        let num_rows = equations.len() + 1;
+        let num_cols = variables.len() + 1;
+        let mut matrix = DMatrix::zeros(num_rows, num_cols);
+        for (r, expression) in equations
+            .iter()
+            .map(|(expression, _)| expression)
+            .chain([&objective])
+            .enumerate()
+        {
+            for (c, variable) in variables.iter().enumerate() {
+                matrix[(r, c)] = expression.coefficient(variable);
+            }
+        }
+        for (r, (_, rhs)) in equations.iter().enumerate() {
+            matrix[(r, num_cols - 1)] = *rhs;
+        }
+
+        let mut row_names: Vec<String> = (0..equations.len()).map(|i| format!("c{}", i)).collect();
+        row_names.push("z".to_string());
+        let mut column_names: Vec<String> =
+            variables.iter().map(|v| v.name().to_string()).collect();
+        column_names.push("rhs".to_string());
+
+        let mut tableau = Tableau::new(matrix, row_names, column_names);
+        // Without an identity basis the basis is simply not tracked.
+        let _ = tableau.identify_basis_from_identity_columns();
+        Ok(tableau)
+    }
+
+    /// Create the initial tableau of a problem and track the slack variables of its constraints.
+    ///
+    /// # Arguments
+    /// * `problem` - The problem to build the tableau for.
+    ///
+    /// # Returns
+    /// The initial tableau and a map from constraint name to the column index of its
+    /// slack or surplus variable. The constraints are named `c0`, `c1`, ... as in the LP file format.
+    ///
+    /// # Note
+    /// The tableau is built with `SimplexTableauBuilder::from_problem`. Only `<=` and `>=`
+    /// constraints have a slack or surplus variable, so equality constraints are not part of the map.
+    pub fn from_problem_with_slack_tracking(
+        problem: &Problem,
+    ) -> (Tableau, HashMap<String, usize>) {
+        let builder = SimplexTableauBuilder::from_problem(problem);
+        let slack_columns = builder
+            .slack_columns()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, col)| col.map(|col| (format!("c{}", i), col)))
+            .collect();
+        let (tableau, _) = builder.build();
+        (tableau, slack_columns)
     }
 
     /// Get the matrix of the tableau.
@@ -56,6 +458,28 @@ impl Tableau {
         &self.matrix
     }
     
+    /// Copy the matrix of the tableau into a Matrix2D.
+    ///
+    /// # Returns
+    /// A Matrix2D with the same elements as the tableau.
+    pub fn to_matrix2d(&self) -> Matrix2D<f64> {
+        Matrix2D::from_dmatrix(&self.matrix)
+    }
+
+    /// Export the tableau as a dictionary.
+    ///
+    /// # Returns
+    /// The entries of the tableau keyed by `(row_name, column_name)`.
+    pub fn export_as_dict(&self) -> HashMap<(String, String), f64> {
+        let mut dict = HashMap::with_capacity(self.rows() * self.cols());
+        for (r, row) in self.row_names.iter().enumerate() {
+            for (c, column) in self.column_names.iter().enumerate() {
+                dict.insert((row.clone(), column.clone()), self.matrix[(r, c)]);
+            }
+        }
+        dict
+    }
+
     /// Get the number of rows of the tableau.
     ///
     /// # Returns
@@ -72,6 +496,22 @@ impl Tableau {
         self.matrix.ncols()
     }
     
+    /// Get the number of columns of the tableau without the rhs column.
+    ///
+    /// # Returns
+    /// The number of structural, slack, surplus and artificial columns.
+    pub fn column_count_active(&self) -> usize {
+        self.cols() - 1
+    }
+
+    /// Get the number of constraint rows of the tableau.
+    ///
+    /// # Returns
+    /// The number of rows without the objective row.
+    pub fn constraint_count(&self) -> usize {
+        self.rows() - 1
+    }
+
     /// Get the names of the columns of the tableau.
     ///
     /// # Returns
@@ -104,115 +544,1640 @@ impl Tableau {
         &mut self.column_names
     }
     
-    /// Get the objective value of the tableau.
+    /// Set the name of a single row of the tableau.
+    ///
+    /// # Arguments
+    /// * `row` - The index of the row.
+    /// * `name` - The new name of the row.
     ///
     /// # Returns
-    /// The objective value of the tableau.
-    pub fn get_objective_value(&self) -> f64 {
-        self.matrix[(self.rows() - 1, self.cols() - 1)]
+    /// - `Ok(())` if the name was set.
+    /// - `Err(TableauError::RowOutOfBounds)` if the row is out of bounds.
+    pub fn set_row_name(&mut self, row: usize, name: String) -> Result<(), TableauError> {
+        let row_name = self
+            .row_names
+            .get_mut(row)
+            .ok_or(TableauError::RowOutOfBounds(row))?;
+        *row_name = name;
+        Ok(())
     }
-    
-    /// Get the rhs vector of the tableau.
+
+    /// Set the name of a single column of the tableau.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    /// * `name` - The new name of the column.
     ///
+    /// # Returns
+    /// - `Ok(())` if the name was set.
+    /// - `Err(TableauError::ColumnOutOfBounds)` if the column is out of bounds.
+    pub fn set_column_name(&mut self, col: usize, name: String) -> Result<(), TableauError> {
+        let column_name = self
+            .column_names
+            .get_mut(col)
+            .ok_or(TableauError::ColumnOutOfBounds(col))?;
+        *column_name = name;
+        Ok(())
+    }
+
+    /// Get the tolerance used when comparing values of the tableau against zero.
     ///
     /// # Returns
-    /// The rhs vector of the tableau.
-    /// 
+    /// The tolerance, which defaults to `1e-9`.
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Set the tolerance used when comparing values of the tableau against zero.
+    ///
+    /// # Arguments
+    /// * `tol` - The new tolerance.
+    pub fn set_tolerance(&mut self, tol: f64) {
+        self.tolerance = tol;
+    }
+
+    /// Get the basis of the tableau.
+    ///
+    /// # Returns
+    /// - `Some(basis)` with the index of the basic column of each constraint row.
+    /// - `None` if the basis is not tracked.
+    pub fn basis(&self) -> Option<&Vec<usize>> {
+        self.basis.as_ref()
+    }
+
+    /// Set the basis of the tableau.
+    ///
+    /// # Arguments
+    /// * `basis` - The index of the basic column of each constraint row.
+    ///
     /// # Note
-    /// The rhs vector is the last column of the matrix without the last row.
-    pub fn rhs_vector(&self) -> MatrixView<f64, Dyn, Dyn, U1, Dyn> {
-        // The rhs vector is the last column of the matrix without the last row.
-        self.matrix.view((0, self.cols() - 1), (self.rows() - 1, 1))
+    /// Once set, the basis is updated by every pivot operation.
+    pub fn set_basis(&mut self, basis: Vec<usize>) {
+        self.basis = Some(basis);
+        self.basis_inverse = None;
     }
-    
-    /// Get the objective coefficients of the tableau.
-    /// 
+
+    /// Get the pivot operations that were performed on the tableau.
+    ///
     /// # Returns
-    /// The objective coefficients of the tableau.
-    /// 
+    /// The pivot operations in the order in which they were performed.
+    pub fn history(&self) -> &Vec<PivotStep> {
+        &self.history
+    }
+
+    /// Clear the history of pivot operations.
+    ///
     /// # Note
-    /// The objective coefficients are the last row of the matrix without the last column.
-    pub fn objective_coefficients(&self) -> MatrixView<f64, Dyn, Dyn, U1, Dyn> {
-        // The objective coefficients are the last row of the matrix without the last column.
-        self.matrix.view((self.rows() - 1, 0), (1, self.cols() - 1))
+    /// This also resets the pivot count and the pivot log.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.pivot_count = 0;
+        self.pivot_log.clear();
     }
-    
-    ///Check if the current tableau is feasible.
+
+    /// Enable the timing of pivot operations.
+    ///
+    /// # Note
+    /// Once enabled, every call to `gaussian_pivot` is recorded in the pivot log.
+    pub fn enable_timing(&mut self) {
+        self.timing_enabled = true;
+    }
+
+    /// Get the timed pivot operations.
     ///
     /// # Returns
-    /// - `true` if the tableau is feasible.
-    /// - `false` if the tableau is not feasible.
-    pub fn is_feasible(&self) -> bool {
-        let rhs_vector = self.rhs_vector();
-        
-        // Check if all values of the rhs vector are greater or equal to zero.
-        rhs_vector.iter().all(|value| *value >= 0.0)
+    /// The timed pivot operations since timing was enabled.
+    pub fn pivot_log(&self) -> &[PivotLog] {
+        &self.pivot_log
     }
-    
-    /// Check if the tableau is optimal.
+
+    /// Get the number of pivot operations that were performed on the tableau.
     ///
     /// # Returns
-    /// - `true` if the tableau is optimal.
-    /// - `false` if the tableau is not optimal.
-    pub fn is_optimal(&self) -> bool {
-        let objective_coefficients = self.objective_coefficients();
-        
-        // Check if all values of the objective are greater or equal to zero.
-        objective_coefficients.iter().all(|value| *value >= 0.0)
+    /// The number of calls to `gaussian_pivot` since the last call to `clear_history`.
+    pub fn total_pivot_count(&self) -> usize {
+        self.pivot_count
     }
 
-    /// Perform a pivot operation on the tableau.
+    /// Identify the basis of the tableau from its identity columns.
     ///
-    /// # Arguments
-    /// * `pivot_row` - The index of the pivot row.
-    /// * `pivot_column` - The index of the pivot column.
+    /// # Returns
+    /// - `Ok(())` if every constraint row has a basic column. The basis is tracked afterwards.
+    /// - `Err(TableauError::NoBasisFound)` otherwise. The tracked basis is left unchanged.
+    pub fn identify_basis_from_identity_columns(&mut self) -> Result<(), TableauError> {
+        let basis = (0..self.rows() - 1)
+            .map(|r| self.basic_column(r))
+            .collect::<Option<Vec<usize>>>()
+            .ok_or(TableauError::NoBasisFound)?;
+
+        self.set_basis(basis);
+        Ok(())
+    }
+
+    /// Verify that the dimensions of the tableau are consistent.
     ///
-    /// # Note
-    /// The pivot operation is performed in place using the gaussian elimination method.
-    pub fn gaussian_pivot(&mut self, pivot_row: usize, pivot_column: usize) {
-        // Get the pivot element.
-        let pivot_element = self.matrix[(pivot_row, pivot_column)];
+    /// # Returns
+    /// - `Ok(())` if the tableau is consistent.
+    /// - `Err(ConsistencyError)` describing the first violated invariant.
+    pub fn verify_consistency(&self) -> Result<(), ConsistencyError> {
+        if self.row_names.len() != self.rows() {
+            return Err(ConsistencyError::RowNameCountMismatch {
+                expected: self.rows(),
+                actual: self.row_names.len(),
+            });
+        }
 
-        // Scale the pivot row so that the pivot element becomes 1.
-        let num_cols = self.cols();
-        for c in 0..num_cols {
-            self.matrix[(pivot_row, c)] *= 1.0 / pivot_element;
+        if self.column_names.len() != self.cols() {
+            return Err(ConsistencyError::ColumnNameCountMismatch {
+                expected: self.cols(),
+                actual: self.column_names.len(),
+            });
         }
 
-        // Perform row operations to eliminate other entries in the pivot column.
-        let num_rows = self.rows();
-        for r in 0..num_rows {
-            if r != pivot_row {
-                let factor = self.matrix[(r, pivot_column)];
-                for c in 0..num_cols {
-                    self.matrix[(r, c)] -= factor * self.matrix[(pivot_row, c)];
-                }
+        // The basis has one entry per constraint row, i.e. all rows except the objective row.
+        if let Some(basis) = &self.basis {
+            if basis.len() != self.rows() - 1 {
+                return Err(ConsistencyError::BasisLengthMismatch {
+                    expected: self.rows() - 1,
+                    actual: basis.len(),
+                });
             }
         }
+
+        Ok(())
     }
-}
 
-/// Implement the Display trait for Tableau.
-/// 
-/// # Note
-/// This implementation uses the tabled crate to display the tableau
-/// in a well formatted table.
-impl Display for Tableau {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let mut builder = tabled::builder::Builder::default();
-        
-        // Push the column headers to the table.
-        // The first cell (0, 0) is empty, because this column is used for the row names.
-        let mut column_header = vec![String::new()];
-        column_header.extend(self.column_names.iter().cloned());
-        builder.push_record(column_header);
-        
-        // Push the rows to the table.
-        // The first cell of each row is the row name.
-        for (i, row) in self.matrix.row_iter().enumerate() {
-            let mut row_data = Vec::new();
-            row_data.push(self.row_names[i].to_string());
-            row_data.extend(row.iter().map(|value| value.to_string()));
+    /// Add a column in front of the rhs column.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the column.
+    /// * `column` - The entries of the column, including the objective row.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the column was added.
+    /// - `Err(TableauError::DimensionMismatch)` if the length of the column
+    ///   does not match the number of rows.
+    ///
+    /// # Note
+    /// The column has to be expressed in terms of the current basis.
+    /// The indices of all other columns except the rhs column remain valid.
+    pub fn add_column(&mut self, name: String, column: DVector<f64>) -> Result<(), TableauError> {
+        if column.len() != self.rows() {
+            return Err(TableauError::DimensionMismatch {
+                expected: self.rows(),
+                actual: column.len(),
+            });
+        }
+
+        // Keep the original matrix in sync, so the tableau can still be refactorized.
+        let original_column = self.original_column(&column);
+        let rhs_column = self.cols() - 1;
+        let original = std::mem::replace(&mut self.original, DMatrix::zeros(0, 0));
+        self.original = original.insert_column(rhs_column, 0.0);
+        self.original.set_column(rhs_column, &original_column);
+
+        let matrix = std::mem::replace(&mut self.matrix, DMatrix::zeros(0, 0));
+        self.matrix = matrix.insert_column(rhs_column, 0.0);
+        self.matrix.set_column(rhs_column, &column);
+        self.column_names.insert(rhs_column, name);
+        Ok(())
+    }
+
+    /// Express a column of the current tableau in terms of the original matrix.
+    ///
+    /// # Arguments
+    /// * `column` - The entries of the column in terms of the current basis.
+    ///
+    /// # Returns
+    /// The entries of the column in terms of the original matrix.
+    ///
+    /// # Note
+    /// If the basis is not tracked, the current basis is assumed to be the original one
+    /// and the column is returned unchanged.
+    fn original_column(&self, column: &DVector<f64>) -> DVector<f64> {
+        let Some(basis) = &self.basis else {
+            return column.clone();
+        };
+
+        // The original constraint column is `B x` and the original objective entry
+        // is `x_obj + c_B x`, where `x` is the column in terms of the current basis.
+        let m = self.rows() - 1;
+        let mut original = DVector::zeros(self.rows());
+        let mut objective = column[m];
+        for (r, &col) in basis.iter().enumerate() {
+            original
+                .rows_mut(0, m)
+                .axpy(column[r], &self.original.column(col).rows(0, m), 1.0);
+            objective += self.original[(m, col)] * column[r];
+        }
+        original[m] = objective;
+        original
+    }
+
+    /// Add a constraint row to the tableau.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the row.
+    /// * `row` - The entries of the row in all columns except the rhs column.
+    /// * `rhs` - The rhs of the row.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the row was added.
+    /// - `Err(TableauError::DimensionMismatch)` if the length of the row
+    ///   does not match the number of columns without the rhs column.
+    ///
+    /// # Note
+    /// The row is inserted above the objective row, so the objective row moves down by one.
+    /// If the basis is tracked, the column that is basic in the new row is added to it.
+    /// The basis is no longer tracked if no column of the new row has identity structure,
+    /// e.g. if a column for the slack variable of the row has not been added yet.
+    /// In that case the basis can be restored with `identify_basis_from_identity_columns`.
+    pub fn add_constraint_row(
+        &mut self,
+        name: String,
+        row: DVector<f64>,
+        rhs: f64,
+    ) -> Result<(), TableauError> {
+        if row.len() != self.cols() - 1 {
+            return Err(TableauError::DimensionMismatch {
+                expected: self.cols() - 1,
+                actual: row.len(),
+            });
+        }
+
+        let entries = row.transpose().insert_column(row.len(), rhs);
+
+        // A constraint over the same variables is valid in terms of the original matrix as well.
+        let objective_row = self.rows() - 1;
+        let original = std::mem::replace(&mut self.original, DMatrix::zeros(0, 0));
+        self.original = original.insert_row(objective_row, 0.0);
+        self.original.row_mut(objective_row).copy_from(&entries);
+
+        let matrix = std::mem::replace(&mut self.matrix, DMatrix::zeros(0, 0));
+        self.matrix = matrix.insert_row(objective_row, 0.0);
+        self.matrix.row_mut(objective_row).copy_from(&entries);
+        self.row_names.insert(objective_row, name);
+        self.basis_inverse = None;
+        self.perturbation = self
+            .perturbation
+            .take()
+            .map(|perturbation| perturbation.insert_row(objective_row, 0.0));
+
+        if let Some(basis) = &self.basis {
+            let basic_column = (0..self.cols() - 1)
+                .find(|&c| !basis.contains(&c) && self.basic_row(c) == Some(objective_row));
+            self.basis = basic_column.map(|column| {
+                let mut basis = basis.clone();
+                basis.push(column);
+                basis
+            });
+        }
+        Ok(())
+    }
+
+    /// Get the index of the objective row.
+    ///
+    /// # Returns
+    /// The index of the objective row, which is always the last row of the tableau.
+    pub fn objective_row_index(&self) -> usize {
+        self.rows() - 1
+    }
+
+    /// Designate a row as the objective row.
+    ///
+    /// # Arguments
+    /// * `row` - The index of the row that is the objective row.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the row is now the objective row.
+    /// - `Err(TableauError::RowOutOfBounds)` if the row is out of bounds.
+    ///
+    /// # Note
+    /// The row is swapped with the last row, including its name, since the objective row
+    /// is always the last row of the tableau. If the rows were swapped, the basis is no longer
+    /// tracked and can be restored with `identify_basis_from_identity_columns`.
+    pub fn set_objective_row_index(&mut self, row: usize) -> Result<(), TableauError> {
+        if row >= self.rows() {
+            return Err(TableauError::RowOutOfBounds(row));
+        }
+
+        let objective_row = self.objective_row_index();
+        if row != objective_row {
+            self.matrix.swap_rows(row, objective_row);
+            self.original.swap_rows(row, objective_row);
+            self.row_names.swap(row, objective_row);
+            if let Some(perturbation) = &mut self.perturbation {
+                perturbation.swap_rows(row, objective_row);
+            }
+            self.basis = None;
+            self.basis_inverse = None;
+        }
+        Ok(())
+    }
+
+    /// Recompute the tableau from the original matrix and the current basis.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the tableau was recomputed.
+    /// - `Err(TableauError::NoBasisFound)` if the basis is not tracked.
+    /// - `Err(TableauError::SingularBasis)` if the basis columns are linearly dependent.
+    ///
+    /// # Note
+    /// The constraint rows are recomputed by solving `B x = a` for every original column `a`,
+    /// where `B` consists of the original basis columns. The objective row is then priced out
+    /// for the current basis. This removes the floating point error accumulated by many pivots.
+    /// Changes made to the matrix through setters since construction are discarded,
+    /// and so is a perturbation of the rhs.
+    pub fn refactorize(&mut self) -> Result<(), TableauError> {
+        let basis = self.basis.clone().ok_or(TableauError::NoBasisFound)?;
+        self.recompute_for_basis(&basis)
+    }
+
+    /// Replace the basis and recompute the tableau for the new basis.
+    ///
+    /// # Arguments
+    /// * `basis` - The index of the basic column of each constraint row.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the tableau was recomputed for the new basis.
+    /// - `Err(TableauError::DimensionMismatch)` if the basis does not have one column per constraint row.
+    /// - `Err(TableauError::ColumnOutOfBounds)` if a basic column is out of bounds.
+    /// - `Err(TableauError::SingularBasis)` if the basis columns are linearly dependent.
+    ///
+    /// # Note
+    /// The tableau is recomputed as in `refactorize`. If an error is returned,
+    /// the tableau, its basis and its stored basis inverse are left unchanged.
+    pub fn refactorize_with_basis(&mut self, basis: Vec<usize>) -> Result<(), TableauError> {
+        let constraint_rows = self.rows() - 1;
+        if basis.len() != constraint_rows {
+            return Err(TableauError::DimensionMismatch {
+                expected: constraint_rows,
+                actual: basis.len(),
+            });
+        }
+        if let Some(&col) = basis.iter().find(|&&col| col >= self.cols() - 1) {
+            return Err(TableauError::ColumnOutOfBounds(col));
+        }
+
+        self.recompute_for_basis(&basis)?;
+        self.set_basis(basis);
+        Ok(())
+    }
+
+    /// Recompute the tableau from the original matrix for a basis.
+    ///
+    /// # Arguments
+    /// * `basis` - The index of the basic column of each constraint row.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the tableau was recomputed.
+    /// - `Err(TableauError::SingularBasis)` if the basis columns are linearly dependent,
+    ///   in which case the tableau is left unchanged.
+    fn recompute_for_basis(&mut self, basis: &[usize]) -> Result<(), TableauError> {
+        let m = self.rows() - 1;
+
+        let basis_matrix = self.basis_matrix(basis);
+        let constraints = self.original.rows(0, m).clone_owned();
+        let solved = basis_matrix
+            .lu()
+            .solve(&constraints)
+            .ok_or(TableauError::SingularBasis)?;
+
+        // Price out the objective row: `r_j = r0_j - r0_B x_j`.
+        let basis_costs = DVector::from_fn(m, |r, _| self.original[(m, basis[r])]);
+        let objective = self.original.row(m) - basis_costs.transpose() * &solved;
+
+        self.matrix.rows_mut(0, m).copy_from(&solved);
+        self.matrix.row_mut(m).copy_from(&objective);
+        self.perturbation = None;
+        Ok(())
+    }
+
+    /// Get the original columns of a basis.
+    ///
+    /// # Arguments
+    /// * `basis` - The index of the basic column of each constraint row.
+    ///
+    /// # Returns
+    /// The matrix `B` of the constraint rows of the original basis columns.
+    fn basis_matrix(&self, basis: &[usize]) -> DMatrix<f64> {
+        let m = self.rows() - 1;
+        DMatrix::from_fn(m, m, |r, c| self.original[(r, basis[c])])
+    }
+
+    /// Get the stored basis inverse.
+    ///
+    /// # Returns
+    /// - `Some(inverse)` of the original basis columns, if it was computed.
+    /// - `None` otherwise.
+    pub fn basis_inverse(&self) -> Option<&DMatrix<f64>> {
+        self.basis_inverse.as_ref()
+    }
+
+    /// Compute and store the inverse of the original columns of the current basis.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the basis inverse was computed.
+    /// - `Err(TableauError::NoBasisFound)` if the basis is not tracked.
+    /// - `Err(TableauError::SingularBasis)` if the basis columns are linearly dependent.
+    ///
+    /// # Note
+    /// The stored inverse is updated by every pivot operation with `apply_eta_update`,
+    /// and it is discarded whenever the basis is replaced or rows are changed.
+    pub fn compute_basis_inverse(&mut self) -> Result<(), TableauError> {
+        let basis = self.basis.as_ref().ok_or(TableauError::NoBasisFound)?;
+        let inverse = self
+            .basis_matrix(basis)
+            .try_inverse()
+            .ok_or(TableauError::SingularBasis)?;
+        self.basis_inverse = Some(inverse);
+        Ok(())
+    }
+
+    /// Update the stored basis inverse for a basis change with an eta matrix.
+    ///
+    /// # Arguments
+    /// * `entering_col` - The index of the column that enters the basis.
+    /// * `leaving_row` - The index of the constraint row whose basic column leaves the basis.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the basis inverse was updated.
+    /// - `Err(TableauError::ColumnOutOfBounds)` if the column is out of bounds.
+    /// - `Err(TableauError::RowOutOfBounds)` if the row is not a constraint row.
+    /// - `Err(TableauError::NoBasisFound)` if no basis inverse is stored.
+    /// - `Err(TableauError::SingularBasis)` if the new basis would be singular.
+    ///
+    /// # Note
+    /// With `d = B^{-1} a`, where `a` is the original entering column, the leaving row of the
+    /// inverse is divided by `d_r`, and `d_i` times the new leaving row is subtracted from every
+    /// other row `i`. This takes `O(m^2)` instead of the `O(m^3)` of `compute_basis_inverse`.
+    /// Only the stored inverse is updated, not the matrix or the basis. `gaussian_pivot` applies
+    /// this update itself, so it only has to be called for basis changes without a pivot operation.
+    pub fn apply_eta_update(
+        &mut self,
+        entering_col: usize,
+        leaving_row: usize,
+    ) -> Result<(), TableauError> {
+        if entering_col >= self.cols() - 1 {
+            return Err(TableauError::ColumnOutOfBounds(entering_col));
+        }
+        let m = self.rows() - 1;
+        if leaving_row >= m {
+            return Err(TableauError::RowOutOfBounds(leaving_row));
+        }
+        let inverse = self
+            .basis_inverse
+            .as_mut()
+            .ok_or(TableauError::NoBasisFound)?;
+
+        let eta = &*inverse * self.original.view((0, entering_col), (m, 1));
+        let pivot = eta[leaving_row];
+        if pivot.abs() <= self.tolerance {
+            return Err(TableauError::SingularBasis);
+        }
+
+        let pivot_row = inverse.row(leaving_row) / pivot;
+        for r in 0..m {
+            if r != leaving_row && eta[r] != 0.0 {
+                let mut row = inverse.row_mut(r);
+                row -= &pivot_row * eta[r];
+            }
+        }
+        inverse.row_mut(leaving_row).copy_from(&pivot_row);
+        Ok(())
+    }
+
+    /// Get the objective value of the tableau.
+    ///
+    /// # Returns
+    /// The objective value of the tableau.
+    #[deprecated(note = "use `objective_value` instead")]
+    pub fn get_objective_value(&self) -> f64 {
+        self.objective_value()
+    }
+
+    /// Get the objective value of the tableau.
+    ///
+    /// # Returns
+    /// The objective value of the tableau.
+    ///
+    /// # Note
+    /// The objective value is stored in the bottom right cell of the matrix.
+    pub fn objective_value(&self) -> f64 {
+        self.matrix[(self.rows() - 1, self.cols() - 1)]
+    }
+
+    /// Set the objective value of the tableau.
+    ///
+    /// # Arguments
+    /// * `value` - The new objective value, e.g. the constant term of the objective function.
+    pub fn set_objective_value(&mut self, value: f64) {
+        let (row, col) = (self.rows() - 1, self.cols() - 1);
+        self.matrix[(row, col)] = value;
+    }
+
+    /// Get the dual objective value of the current basis.
+    ///
+    /// # Returns
+    /// The value `y^T b = c_B^T B^{-1} b` of the dual prices `y^T = c_B^T B^{-1}`,
+    /// in the sense of the maximization problem represented by the tableau.
+    ///
+    /// # Note
+    /// The costs `c_B` of the basic columns are taken from the original objective row,
+    /// and `B^{-1} b` is the current rhs vector. The tracked basis is used if available,
+    /// otherwise basic columns are detected by their identity structure, and rows without
+    /// a basic column are skipped. At an optimal tableau the dual objective value equals
+    /// the primal objective value by strong duality.
+    pub fn dual_objective_value(&self) -> f64 {
+        let objective_row = self.rows() - 1;
+        let rhs_column = self.cols() - 1;
+        (0..objective_row)
+            .filter_map(|r| {
+                let col = match &self.basis {
+                    Some(basis) => basis.get(r).copied(),
+                    None => self.basic_column(r),
+                }?;
+                // The objective row holds the negated costs of the maximization problem.
+                Some(-self.original[(objective_row, col)] * self.matrix[(r, rhs_column)])
+            })
+            .sum()
+    }
+    
+    /// Get the rhs vector of the tableau.
+    ///
+    ///
+    /// # Returns
+    /// The rhs vector of the tableau.
+    /// 
+    /// # Note
+    /// The rhs vector is the last column of the matrix without the last row.
+    pub fn rhs_vector(&self) -> MatrixView<'_, f64, Dyn, Dyn, U1, Dyn> {
+        // The rhs vector is the last column of the matrix without the last row.
+        self.matrix.view((0, self.cols() - 1), (self.rows() - 1, 1))
+    }
+    
+    /// Get the constraint row with the largest rhs.
+    ///
+    /// # Returns
+    /// - `Some(row)` of the first constraint row with the largest rhs.
+    /// - `None` if the tableau has no constraint rows.
+    pub fn max_rhs_row(&self) -> Option<usize> {
+        let rhs_vector = self.rhs_vector();
+        (0..rhs_vector.nrows()).reduce(|best, r| {
+            if rhs_vector[r] > rhs_vector[best] {
+                r
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Get the constraint row with the smallest rhs.
+    ///
+    /// # Returns
+    /// - `Some(row)` of the first constraint row with the smallest rhs.
+    /// - `None` if the tableau has no constraint rows.
+    ///
+    /// # Note
+    /// In the dual simplex method, this is the leaving row.
+    pub fn min_rhs_row(&self) -> Option<usize> {
+        let rhs_vector = self.rhs_vector();
+        (0..rhs_vector.nrows()).reduce(|best, r| {
+            if rhs_vector[r] < rhs_vector[best] {
+                r
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Get the left hand side matrix of the tableau.
+    ///
+    /// # Returns
+    /// The left hand side matrix of the tableau.
+    ///
+    /// # Note
+    /// The left hand side matrix consists of all rows, including the objective row,
+    /// and all columns except the rhs column.
+    pub fn lhs_matrix(&self) -> MatrixView<'_, f64, Dyn, Dyn, U1, Dyn> {
+        self.matrix.view((0, 0), (self.rows(), self.cols() - 1))
+    }
+
+    /// Get the objective coefficients of the tableau.
+    /// 
+    /// # Returns
+    /// The objective coefficients of the tableau.
+    /// 
+    /// # Note
+    /// The objective coefficients are the last row of the matrix without the last column.
+    pub fn objective_coefficients(&self) -> MatrixView<'_, f64, Dyn, Dyn, U1, Dyn> {
+        // The objective coefficients are the last row of the matrix without the last column.
+        self.matrix.view((self.rows() - 1, 0), (1, self.cols() - 1))
+    }
+    
+    /// Get a single entry of the objective row.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    ///
+    /// # Returns
+    /// - `Some(value)` of the objective row in the given column.
+    /// - `None` if the column is out of bounds.
+    pub fn obj_row_entry(&self, col: usize) -> Option<f64> {
+        if col >= self.cols() {
+            return None;
+        }
+        Some(self.matrix[(self.rows() - 1, col)])
+    }
+
+    /// Set a single entry of the objective row.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    /// * `value` - The new value.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the entry was set.
+    /// - `Err(TableauError::ColumnOutOfBounds)` if the column is out of bounds.
+    pub fn set_obj_row_entry(&mut self, col: usize, value: f64) -> Result<(), TableauError> {
+        if col >= self.cols() {
+            return Err(TableauError::ColumnOutOfBounds(col));
+        }
+        let row = self.rows() - 1;
+        self.matrix[(row, col)] = value;
+        Ok(())
+    }
+
+    /// Replace the objective row and re-price it for the current basis.
+    ///
+    /// # Arguments
+    /// * `new_costs` - The new objective row entries of all columns except the rhs column,
+    ///   in the same form as the initial objective row, e.g. `-c` for a maximization.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the objective row was updated.
+    /// - `Err(TableauError::DimensionMismatch)` if there is not exactly one cost per column.
+    /// - `Err(TableauError::NoBasisFound)` if the basis is not tracked.
+    ///
+    /// # Note
+    /// The constraint rows stay valid, so only the objective row has to be recomputed.
+    /// The entries of the basic columns are eliminated from the new objective row, which
+    /// yields the reduced costs and the objective value for the current basis.
+    pub fn update_objective_row(&mut self, new_costs: &[f64]) -> Result<(), TableauError> {
+        let rhs_column = self.cols() - 1;
+        if new_costs.len() != rhs_column {
+            return Err(TableauError::DimensionMismatch {
+                expected: rhs_column,
+                actual: new_costs.len(),
+            });
+        }
+        let basis = self.basis.clone().ok_or(TableauError::NoBasisFound)?;
+
+        let m = self.rows() - 1;
+        let mut objective = DVector::zeros(self.cols());
+        objective.rows_mut(0, rhs_column).copy_from_slice(new_costs);
+
+        // Keep the original objective row in sync, so the tableau can still be refactorized.
+        self.original.row_mut(m).copy_from(&objective.transpose());
+
+        for (r, &col) in basis.iter().enumerate() {
+            let cost = objective[col];
+            if cost != 0.0 {
+                objective.axpy(-cost, &self.matrix.row(r).transpose(), 1.0);
+            }
+        }
+        self.matrix.row_mut(m).copy_from(&objective.transpose());
+
+        // The objective value is priced out with the perturbed rhs.
+        if let Some(perturbation) = &mut self.perturbation {
+            perturbation[m] = -basis
+                .iter()
+                .enumerate()
+                .map(|(r, &col)| new_costs[col] * perturbation[r])
+                .sum::<f64>();
+        }
+        Ok(())
+    }
+
+    /// Get the objective row entry of a variable.
+    ///
+    /// # Arguments
+    /// * `var_name` - The name of the column of the variable.
+    ///
+    /// # Returns
+    /// - `Some(value)` of the objective row in the column of the variable.
+    /// - `None` if no column has the given name.
+    pub fn objective_coefficient_for_variable(&self, var_name: &str) -> Option<f64> {
+        self.obj_row_entry(self.col_index_by_name(var_name)?)
+    }
+
+    /// Convert a row of the tableau into a linear expression.
+    ///
+    /// # Arguments
+    /// * `row` - The index of the row.
+    ///
+    /// # Returns
+    /// - `Some(expression)` with one term per column, using the column names as variable names.
+    /// - `None` if the row is out of bounds.
+    ///
+    /// # Note
+    /// The rhs column and entries that are zero within the tolerance are omitted.
+    pub fn row_as_expression(&self, row: usize) -> Option<LinearExpression> {
+        if row >= self.rows() {
+            return None;
+        }
+
+        let terms = (0..self.cols() - 1)
+            .filter(|&c| self.matrix[(row, c)].abs() > self.tolerance)
+            .map(|c| LinearTerm::new(Variable::new(&self.column_names[c]), self.matrix[(row, c)]))
+            .collect();
+        Some(LinearExpression::new(terms))
+    }
+
+    /// Convert the objective row of the tableau into a linear expression.
+    ///
+    /// # Returns
+    /// The objective row as an expression, using the column names as variable names.
+    ///
+    /// # Note
+    /// The rhs column and entries that are zero within the tolerance are omitted.
+    pub fn objective_as_expression(&self) -> LinearExpression {
+        self.row_as_expression(self.rows() - 1)
+            .expect("The objective row is always part of the tableau.")
+    }
+
+    ///Check if the current tableau is feasible.
+    ///
+    /// # Returns
+    /// - `true` if the tableau is feasible.
+    /// - `false` if the tableau is not feasible.
+    pub fn is_feasible(&self) -> bool {
+        let rhs_vector = self.rhs_vector();
+        
+        // Check if all values of the rhs vector are greater or equal to zero, up to the tolerance.
+        rhs_vector.iter().all(|value| *value >= -self.tolerance)
+    }
+
+    /// Get the total infeasibility of the tableau.
+    ///
+    /// # Returns
+    /// The sum of the absolute values of the negative rhs entries of the constraint rows,
+    /// i.e. `sum_i max(0, -b_i)`, which is zero for a feasible tableau.
+    pub fn feasibility_distance(&self) -> f64 {
+        self.rhs_vector()
+            .iter()
+            .map(|value| (-value).max(0.0))
+            .sum()
+    }
+
+    /// Check if all basic variables have non-negative values.
+    ///
+    /// # Returns
+    /// - `true` if all rhs values of the constraint rows are non-negative.
+    /// - `false` otherwise.
+    ///
+    /// # Note
+    /// This is equivalent to `is_feasible`.
+    pub fn nonnegative_basis_values(&self) -> bool {
+        self.is_feasible()
+    }
+
+    /// Get the constraint rows with a negative rhs value.
+    ///
+    /// # Returns
+    /// The indices of the constraint rows whose rhs is negative beyond the tolerance,
+    /// in ascending order.
+    ///
+    /// # Note
+    /// These are the rows that have to be handled by phase 1 or by the dual simplex method.
+    pub fn negative_basis_row_indices(&self) -> Vec<usize> {
+        self.rhs_vector()
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| **value < -self.tolerance)
+            .map(|(row, _)| row)
+            .collect()
+    }
+    
+    /// Check if the tableau is optimal.
+    ///
+    /// # Returns
+    /// - `true` if the tableau is optimal.
+    /// - `false` if the tableau is not optimal.
+    pub fn is_optimal(&self) -> bool {
+        let objective_coefficients = self.objective_coefficients();
+        
+        // Check if all values of the objective are greater or equal to zero, up to the tolerance.
+        objective_coefficients
+            .iter()
+            .all(|value| *value >= -self.tolerance)
+    }
+
+    /// Get the total violation of the optimality condition of the tableau.
+    ///
+    /// # Returns
+    /// The sum of the absolute values of the negative objective coefficients,
+    /// i.e. `sum_j max(0, -c_j)`, which is zero for an optimal tableau.
+    pub fn optimality_distance(&self) -> f64 {
+        self.objective_coefficients()
+            .iter()
+            .map(|value| (-value).max(0.0))
+            .sum()
+    }
+
+    /// Get the largest absolute value of all entries of the tableau.
+    ///
+    /// # Returns
+    /// The max norm ‖A‖_max of the matrix, i.e. the maximum absolute entry, including the
+    /// objective row and the rhs column. An empty matrix has a norm of zero.
+    pub fn max_absolute_entry(&self) -> f64 {
+        self.matrix.amax()
+    }
+
+    /// Get the Frobenius norm of the tableau.
+    ///
+    /// # Returns
+    /// The square root of the sum of the squares of all entries of the tableau,
+    /// including the objective row and the rhs column.
+    pub fn frobenius_norm(&self) -> f64 {
+        self.matrix.norm()
+    }
+
+    /// Get the index of a column by its name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the column.
+    ///
+    /// # Returns
+    /// - `Some(column)` of the first column with the given name.
+    /// - `None` if no column has the given name.
+    pub fn col_index_by_name(&self, name: &str) -> Option<usize> {
+        self.column_names
+            .iter()
+            .position(|column_name| column_name == name)
+    }
+
+    /// Check if the constraint rows end in an identity matrix.
+    ///
+    /// # Returns
+    /// - `true` if the square block of the constraint rows directly left of the rhs column
+    ///   is an identity matrix, up to the tolerance.
+    /// - `false` otherwise.
+    ///
+    /// # Note
+    /// This is the case for a freshly built tableau whose slack columns form the initial basis,
+    /// since the slack columns are placed last. Any pivot on another column breaks this structure.
+    pub fn is_identity_matrix(&self) -> bool {
+        let m = self.rows() - 1;
+        let n = self.cols() - 1;
+        if n < m {
+            return false;
+        }
+
+        let block = self.matrix.view((0, n - m), (m, m));
+        block.iter().enumerate().all(|(i, value)| {
+            // The view is iterated in column-major order.
+            let expected = if i % m == i / m { 1.0 } else { 0.0 };
+            (value - expected).abs() <= self.tolerance
+        })
+    }
+
+    /// Get the row in which the given column is basic.
+    ///
+    /// # Arguments
+    /// * `column` - The index of the column.
+    ///
+    /// # Returns
+    /// - `Some(row)` if the column is an identity column with its one in `row`.
+    /// - `None` if the column is not basic.
+    ///
+    /// # Note
+    /// A column is basic if it has exactly one entry equal to one in the constraint rows,
+    /// and all other entries, including the one in the objective row, are zero.
+    pub fn basic_row(&self, column: usize) -> Option<usize> {
+        let mut basic_row = None;
+        for (r, value) in self.matrix.column(column).iter().enumerate() {
+            if value.abs() <= self.tolerance {
+                continue;
+            }
+
+            // The one must not be in the objective row and must be the only non-zero entry.
+            if r == self.rows() - 1 || (value - 1.0).abs() > self.tolerance || basic_row.is_some() {
+                return None;
+            }
+            basic_row = Some(r);
+        }
+        basic_row
+    }
+
+    /// Get the index of the basic column of a row.
+    ///
+    /// # Arguments
+    /// * `row` - The index of the constraint row.
+    ///
+    /// # Returns
+    /// - `Some(column)` of the first column that is basic in the row.
+    /// - `None` if no column is basic in the row.
+    pub fn basic_column(&self, row: usize) -> Option<usize> {
+        (0..self.cols() - 1).find(|&col| self.basic_row(col) == Some(row))
+    }
+
+    /// Get the names of the non-basic columns.
+    ///
+    /// # Returns
+    /// The names of all columns except the rhs column that are not identity columns,
+    /// i.e. the candidates for entering the basis.
+    pub fn entering_column_names(&self) -> Vec<&str> {
+        (0..self.cols() - 1)
+            .filter(|&col| self.basic_row(col).is_none())
+            .map(|col| self.column_names[col].as_str())
+            .collect()
+    }
+
+    /// Get the names of the basic columns.
+    ///
+    /// # Returns
+    /// The names of all columns except the rhs column that are identity columns.
+    pub fn basic_variable_names(&self) -> Vec<&str> {
+        (0..self.cols() - 1)
+            .filter(|&col| self.basic_row(col).is_some())
+            .map(|col| self.column_names[col].as_str())
+            .collect()
+    }
+
+    /// Get the current value of the variable of a column.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    ///
+    /// # Returns
+    /// The rhs of the row in which the column is basic, or zero if the column is non-basic.
+    ///
+    /// # Note
+    /// The tracked basis is used if available, otherwise basic columns are detected
+    /// by their identity structure.
+    pub fn column_value(&self, col: usize) -> f64 {
+        let row = match &self.basis {
+            Some(basis) => basis.iter().position(|&basic| basic == col),
+            None => self.basic_row(col),
+        };
+        row.map_or(0.0, |r| self.matrix[(r, self.cols() - 1)])
+    }
+
+    /// Get the activity of a column, i.e. its representation in the current basis.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    ///
+    /// # Returns
+    /// - `Some(activity)` with one entry per constraint row.
+    /// - `None` if the column is out of bounds or is the rhs column.
+    ///
+    /// # Note
+    /// The activity is `B^{-1} a`, where `a` is the original column.
+    /// For a full tableau this is the constraint part of the current column.
+    pub fn column_activity(&self, col: usize) -> Option<DVector<f64>> {
+        if col >= self.cols() - 1 {
+            return None;
+        }
+        Some(
+            self.matrix
+                .view((0, col), (self.rows() - 1, 1))
+                .column(0)
+                .clone_owned(),
+        )
+    }
+
+    /// Get the constraint rows in which a column has a non-zero entry.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    ///
+    /// # Returns
+    /// The indices of the constraint rows whose entry in the column exceeds the tolerance
+    /// in absolute value, in ascending order. The rhs column and out of bounds columns have no entries.
+    pub fn non_zero_constraint_entries(&self, col: usize) -> Vec<usize> {
+        if col >= self.cols() - 1 {
+            return Vec::new();
+        }
+        (0..self.rows() - 1)
+            .filter(|&r| self.matrix[(r, col)].abs() > self.tolerance)
+            .collect()
+    }
+
+    /// Check if a column is zero in all constraint rows.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    ///
+    /// # Returns
+    /// - `true` if all constraint row entries of the column are zero within the tolerance.
+    /// - `false` otherwise, or if the column is out of bounds or is the rhs column.
+    ///
+    /// # Note
+    /// Such a column can not be pivoted into the basis, since every pivot element would be zero.
+    pub fn column_is_zero_in_constraint_rows(&self, col: usize) -> bool {
+        col < self.cols() - 1 && self.non_zero_constraint_entries(col).is_empty()
+    }
+
+    /// Check if increasing the variable of a column is an unbounded direction.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    ///
+    /// # Returns
+    /// - `true` if all constraint row entries of the column are at most the tolerance.
+    /// - `false` otherwise, or if the column is out of bounds or is the rhs column.
+    ///
+    /// # Note
+    /// This is exactly the case in which the minimum ratio test finds no leaving row.
+    /// If the column also has a negative objective coefficient, the problem is unbounded.
+    pub fn is_unbounded_direction(&self, col: usize) -> bool {
+        if col >= self.cols() - 1 {
+            return false;
+        }
+        (0..self.rows() - 1).all(|r| self.matrix[(r, col)] <= self.tolerance)
+    }
+
+    /// Format the current basis of the tableau.
+    ///
+    /// # Returns
+    /// The basic variable and its value for every constraint row,
+    /// e.g. `Row 0: x1 = 3, Row 1: s2 = 1.5`.
+    ///
+    /// # Note
+    /// If no column is basic in a row, the name of the row is used instead.
+    pub fn basis_string(&self) -> String {
+        let rhs_vector = self.rhs_vector();
+        (0..self.rows() - 1)
+            .map(|r| {
+                let name = match self.basic_column(r) {
+                    Some(col) => &self.column_names[col],
+                    None => &self.row_names[r],
+                };
+                format!("Row {}: {} = {}", r, name, rhs_vector[r])
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// Print the current basis of the tableau.
+    ///
+    /// # Note
+    /// This is a much shorter alternative to printing the whole tableau,
+    /// which makes it suitable for printing in every iteration.
+    pub fn print_basis(&self) {
+        println!("{}", self.basis_string());
+    }
+
+    /// Check if phase 1 of the two phase simplex method is complete.
+    ///
+    /// # Arguments
+    /// * `artificial_cols` - The indices of the columns of the artificial variables.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if the phase 1 objective value is zero and no artificial variable
+    ///   is basic with a positive value.
+    /// - `Ok(false)` otherwise.
+    /// - `Err(TableauError::ColumnOutOfBounds)` if an artificial column is out of bounds.
+    ///
+    /// # Note
+    /// The rhs column can not be an artificial column and is therefore considered out of bounds.
+    pub fn is_phase1_complete(&self, artificial_cols: &[usize]) -> Result<bool, TableauError> {
+        // Check that all artificial columns are valid columns of the tableau.
+        if let Some(&col) = artificial_cols.iter().find(|&&col| col >= self.cols() - 1) {
+            return Err(TableauError::ColumnOutOfBounds(col));
+        }
+
+        // The objective value of phase 1 has to be zero.
+        if self.objective_value().abs() > self.tolerance {
+            return Ok(false);
+        }
+
+        // No artificial variable may remain in the basis with a positive value.
+        let rhs_vector = self.rhs_vector();
+        let artificial_positive = artificial_cols
+            .iter()
+            .filter_map(|&col| self.basic_row(col))
+            .any(|row| rhs_vector[row] > self.tolerance);
+
+        Ok(!artificial_positive)
+    }
+
+    /// Perform the minimum ratio test for a column.
+    ///
+    /// # Arguments
+    /// * `column` - The index of the entering column.
+    ///
+    /// # Returns
+    /// - `Some(row)` of the constraint row with the smallest ratio of rhs to column entry.
+    /// - `None` if the column has no positive entry in the constraint rows.
+    ///
+    /// # Note
+    /// Only rows with a positive entry in the column are considered.
+    /// Ties are broken by choosing the row with the smallest index.
+    pub fn minimum_ratio_row(&self, column: usize) -> Option<usize> {
+        self.ratio_test_results(column).first().map(|&(row, _)| row)
+    }
+
+    /// Get all candidates of the minimum ratio test for a column.
+    ///
+    /// # Arguments
+    /// * `entering_col` - The index of the entering column.
+    ///
+    /// # Returns
+    /// The `(row, ratio)` pairs of all constraint rows with a positive entry in the column,
+    /// sorted by ratio in ascending order. Rows with equal ratios keep their order.
+    pub fn ratio_test_results(&self, entering_col: usize) -> Vec<(usize, f64)> {
+        let rhs_column = self.cols() - 1;
+        let mut results: Vec<(usize, f64)> = (0..self.rows() - 1)
+            .filter(|&r| self.matrix[(r, entering_col)] > self.tolerance)
+            .map(|r| {
+                (
+                    r,
+                    self.matrix[(r, rhs_column)] / self.matrix[(r, entering_col)],
+                )
+            })
+            .collect();
+
+        results.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        results
+    }
+
+    /// Perform the ratio test of the dual simplex method for a leaving row.
+    ///
+    /// # Arguments
+    /// * `leaving_row` - The index of the leaving constraint row.
+    ///
+    /// # Returns
+    /// - `Some(column)` of the entering column.
+    /// - `None` if the row has no negative entry, i.e. the problem is dual unbounded
+    ///   and therefore primal infeasible, or if the row is not a constraint row.
+    ///
+    /// # Note
+    /// Only columns with a negative entry in the leaving row are considered.
+    /// The entering column minimizes the ratio of the objective row entry to the
+    /// absolute value of the entry in the leaving row. Ties are broken by choosing
+    /// the column with the smallest index.
+    pub fn dual_ratio_test(&self, leaving_row: usize) -> Option<usize> {
+        if leaving_row >= self.rows() - 1 {
+            return None;
+        }
+
+        let objective_row = self.rows() - 1;
+        let mut best: Option<(usize, f64)> = None;
+        for c in 0..self.cols() - 1 {
+            let value = self.matrix[(leaving_row, c)];
+            if value >= -self.tolerance {
+                continue;
+            }
+
+            let ratio = self.matrix[(objective_row, c)] / -value;
+            if best.is_none_or(|(_, best_ratio)| ratio < best_ratio) {
+                best = Some((c, ratio));
+            }
+        }
+
+        best.map(|(column, _)| column)
+    }
+
+    /// Get the pivot element at a position.
+    ///
+    /// # Arguments
+    /// * `row` - The index of the pivot row.
+    /// * `col` - The index of the pivot column.
+    ///
+    /// # Returns
+    /// - `Some(value)` of the matrix at the position.
+    /// - `None` if the position is out of bounds.
+    pub fn pivot_element(&self, row: usize, col: usize) -> Option<f64> {
+        self.matrix.get((row, col)).copied()
+    }
+
+    /// Check if a position can be used as a pivot.
+    ///
+    /// # Arguments
+    /// * `row` - The index of the pivot row.
+    /// * `col` - The index of the pivot column.
+    /// * `eps` - The tolerance below which the pivot element is considered to be zero.
+    ///
+    /// # Returns
+    /// - `true` if the position is in bounds and the absolute value of the pivot element exceeds `eps`.
+    /// - `false` otherwise.
+    pub fn is_valid_pivot(&self, row: usize, col: usize, eps: f64) -> bool {
+        self.pivot_element(row, col)
+            .is_some_and(|value| value.abs() > eps)
+    }
+
+    /// Pivot a column into identity form.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the column was pivoted.
+    /// - `Err(TableauError::ColumnOutOfBounds)` if the column is out of bounds.
+    /// - `Err(TableauError::Unbounded)` if the column has no positive entry in the constraint rows.
+    ///
+    /// # Note
+    /// The pivot row is selected using the minimum ratio test, as in `make_column_basic`.
+    pub fn pivot_to_identity(&mut self, col: usize) -> Result<(), TableauError> {
+        self.make_column_basic(col).map(|_| ())
+    }
+
+    /// Make a column basic by pivoting on the row selected by the minimum ratio test.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the entering column.
+    ///
+    /// # Returns
+    /// - `Ok(row)` of the pivot row, whose basic variable left the basis.
+    /// - `Err(TableauError::ColumnOutOfBounds)` if the column is out of bounds.
+    /// - `Err(TableauError::Unbounded)` if the column has no positive entry in the constraint rows,
+    ///   which includes a column that is zero in all constraint rows.
+    ///
+    /// # Note
+    /// Afterwards the column is an identity column with its one in the returned row.
+    pub fn make_column_basic(&mut self, col: usize) -> Result<usize, TableauError> {
+        if col >= self.cols() - 1 {
+            return Err(TableauError::ColumnOutOfBounds(col));
+        }
+
+        let row = self.minimum_ratio_row(col).ok_or(TableauError::Unbounded)?;
+        self.gaussian_pivot(row, col);
+        Ok(row)
+    }
+
+    /// Get the entering column according to Dantzig's rule.
+    ///
+    /// # Returns
+    /// - `Some(column)` with the most negative objective coefficient.
+    /// - `None` if the tableau is optimal.
+    pub fn entering_column(&self) -> Option<usize> {
+        let objective_coefficients = self.objective_coefficients();
+        let mut best: Option<(usize, f64)> = None;
+
+        for (c, &value) in objective_coefficients.iter().enumerate() {
+            if value < -self.tolerance && best.is_none_or(|(_, best_value)| value < best_value) {
+                best = Some((c, value));
+            }
+        }
+
+        best.map(|(column, _)| column)
+    }
+
+    /// Get the entering column according to Dantzig's rule with a custom threshold.
+    ///
+    /// # Arguments
+    /// * `threshold` - The amount by which an objective coefficient has to be negative.
+    ///
+    /// # Returns
+    /// - `Some(column)` with the most negative objective coefficient, if it is below `-threshold`.
+    /// - `None` if all objective coefficients are at least `-threshold`.
+    ///
+    /// # Note
+    /// Skipping columns with a nearly zero reduced cost avoids pivots that barely improve
+    /// the objective value but accumulate floating point error.
+    pub fn canonical_pivot_column_threshold(&self, threshold: f64) -> Option<usize> {
+        let objective_coefficients = self.objective_coefficients();
+        let (column, &value) = objective_coefficients
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))?;
+
+        (value < -threshold).then_some(column)
+    }
+
+    /// Get the entering column according to Bland's rule.
+    ///
+    /// # Returns
+    /// - `Some(column)` of the first column with a negative objective coefficient.
+    /// - `None` if the tableau is optimal.
+    pub fn bland_entering_column(&self) -> Option<usize> {
+        self.objective_coefficients()
+            .iter()
+            .position(|&value| value < -self.tolerance)
+    }
+
+    /// Get the leaving row for an entering column according to Bland's rule.
+    ///
+    /// # Arguments
+    /// * `entering_col` - The index of the entering column.
+    ///
+    /// # Returns
+    /// - `Some(row)` of the leaving constraint row.
+    /// - `None` if the column has no positive entry in the constraint rows.
+    ///
+    /// # Note
+    /// Among all rows with the minimum ratio, the row whose basic variable has the smallest
+    /// column index is chosen. Rows without a basic column are chosen last.
+    pub fn bland_leaving_row(&self, entering_col: usize) -> Option<usize> {
+        let results = self.ratio_test_results(entering_col);
+        let &(_, min_ratio) = results.first()?;
+
+        let basic_column = |row: usize| match &self.basis {
+            Some(basis) => basis.get(row).copied(),
+            None => self.basic_column(row),
+        };
+        results
+            .iter()
+            .take_while(|&&(_, ratio)| ratio - min_ratio <= self.tolerance)
+            .min_by_key(|&&(row, _)| basic_column(row).unwrap_or(usize::MAX))
+            .map(|&(row, _)| row)
+    }
+
+    /// Find the next pivot according to Bland's rule.
+    ///
+    /// # Returns
+    /// - `Some((column, row))` of the next pivot element.
+    /// - `None` if the tableau is optimal or the entering column is unbounded.
+    ///
+    /// # Note
+    /// This combines `bland_entering_column` and `bland_leaving_row`.
+    /// Bland's rule guarantees that the simplex method does not cycle.
+    pub fn find_pivot_bland(&self) -> Option<(usize, usize)> {
+        let column = self.bland_entering_column()?;
+        let row = self.bland_leaving_row(column)?;
+        Some((column, row))
+    }
+
+    /// Perform a single pivot chosen by a custom pivot strategy.
+    ///
+    /// # Arguments
+    /// * `strategy` - A function that returns the `(row, column)` of the next pivot,
+    ///   or `None` if no pivot should be performed.
+    ///
+    /// # Returns
+    /// - `PivotOutcome::Pivoted` if the pivot was performed.
+    /// - `PivotOutcome::NoPivot` if the strategy did not choose a pivot.
+    /// - `PivotOutcome::InvalidPivot` if the pivot is not in a constraint row, is in the
+    ///   rhs column, or its element is zero within the tolerance. This rejects every pivot
+    ///   in a column that is zero in all constraint rows.
+    pub fn pivot_with_strategy<F: Fn(&Tableau) -> Option<(usize, usize)>>(
+        &mut self,
+        strategy: F,
+    ) -> PivotOutcome {
+        let Some((row, column)) = strategy(self) else {
+            return PivotOutcome::NoPivot;
+        };
+
+        let step = PivotStep { row, column };
+        if row >= self.rows() - 1 || column >= self.cols() - 1 {
+            return PivotOutcome::InvalidPivot(step);
+        }
+        if !self.is_valid_pivot(row, column, self.tolerance) {
+            return PivotOutcome::InvalidPivot(step);
+        }
+        self.gaussian_pivot(row, column);
+        PivotOutcome::Pivoted(step)
+    }
+
+    /// Solve the tableau with the simplex method.
+    ///
+    /// # Returns
+    /// - `SimplexResult::Optimal` if the tableau is optimal.
+    /// - `SimplexResult::Unbounded` if the problem is unbounded.
+    ///
+    /// # Note
+    /// The tableau has to be feasible. The entering column is chosen by Dantzig's rule,
+    /// and the leaving row by the minimum ratio test.
+    pub fn solve(&mut self) -> SimplexResult {
+        self.solve_for_n_pivots(usize::MAX)
+    }
+
+    /// Solve the tableau with the simplex method, performing at most `n` pivots.
+    ///
+    /// # Arguments
+    /// * `n` - The maximum number of pivots.
+    ///
+    /// # Returns
+    /// - `SimplexResult::Optimal` if the tableau is optimal.
+    /// - `SimplexResult::Unbounded` if the problem is unbounded.
+    /// - `SimplexResult::Interrupted(pivots)` if the tableau is not yet optimal after `n` pivots.
+    ///
+    /// # Note
+    /// The count of an interrupted result is the number of pivots performed by this call.
+    /// Pivots of earlier calls are not included, they are counted by `total_pivot_count`.
+    /// An interrupted tableau is left in a consistent state,
+    /// so solving can be resumed by calling this method again.
+    pub fn solve_for_n_pivots(&mut self, n: usize) -> SimplexResult {
+        debug_assert_eq!(self.verify_consistency(), Ok(()));
+
+        let mut pivots: u64 = 0;
+        for _ in 0..n {
+            let Some(column) = self.entering_column() else {
+                return SimplexResult::Optimal;
+            };
+            let Some(row) = self.minimum_ratio_row(column) else {
+                return SimplexResult::Unbounded;
+            };
+            self.gaussian_pivot(row, column);
+            pivots += 1;
+        }
+
+        match self.entering_column() {
+            Some(_) => SimplexResult::Interrupted(pivots),
+            None => SimplexResult::Optimal,
+        }
+    }
+
+    /// Perturb the rhs of the constraint rows to prevent cycling.
+    ///
+    /// # Arguments
+    /// * `epsilon` - The perturbation base, which has to be in `(0, 1)`.
+    ///
+    /// # Note
+    /// The value `epsilon^(r + 2)` is added to the rhs of the constraint row with index `r`,
+    /// counting from `r = 0`. Every perturbation is therefore smaller than `epsilon`,
+    /// and no two perturbations are equal, which prevents degenerate ties in the ratio test.
+    /// The perturbation can be removed with `deperturb_rhs`.
+    pub fn anti_cycling_perturb_rhs(&mut self, epsilon: f64) {
+        let rhs_column = self.cols() - 1;
+        let perturbation = self
+            .perturbation
+            .get_or_insert_with(|| DVector::zeros(self.matrix.nrows()));
+        let mut delta = epsilon;
+        for r in 0..self.matrix.nrows() - 1 {
+            delta *= epsilon;
+            self.matrix[(r, rhs_column)] += delta;
+            perturbation[r] += delta;
+        }
+    }
+
+    /// Remove the perturbation of the rhs.
+    ///
+    /// # Note
+    /// The tableau keeps track of the perturbation that was added to the rhs column
+    /// and applies every pivot operation to it as well. Subtracting the tracked perturbation
+    /// therefore yields the rhs, including the objective value, of the unperturbed problem
+    /// for the current basis. Does nothing if the rhs is not perturbed.
+    ///
+    /// This deliberately deviates from rounding every rhs value to the nearest multiple
+    /// of `epsilon`, which would also change unperturbed values that are not such multiples.
+    pub fn deperturb_rhs(&mut self) {
+        let Some(perturbation) = self.perturbation.take() else {
+            return;
+        };
+
+        let rhs_column = self.cols() - 1;
+        let mut rhs = self.matrix.column_mut(rhs_column);
+        rhs -= perturbation;
+    }
+
+    /// Perform a sensitivity analysis for a single variable of an optimal tableau.
+    ///
+    /// # Arguments
+    /// * `var_name` - The name of the column of the variable.
+    ///
+    /// # Returns
+    /// - `Some(result)` with the objective coefficient and rhs ranges.
+    /// - `None` if no column has the given name.
+    ///
+    /// # Note
+    /// The rhs range treats the column of the variable as the slack column of a constraint,
+    /// i.e. as the column of the inverse basis that belongs to this constraint.
+    /// It is therefore only meaningful for slack variables.
+    pub fn sensitivity_ranging_for_variable(&self, var_name: &str) -> Option<RangingResult> {
+        let col = self.col_index_by_name(var_name)?;
+        if col == self.cols() - 1 {
+            return None;
+        }
+
+        let objective_row = self.rows() - 1;
+        let rhs_column = self.cols() - 1;
+
+        // Objective ranging.
+        let (objective_decrease, objective_increase) = match self.basic_row(col) {
+            // Increasing the coefficient of a non-basic variable by its reduced cost
+            // makes the variable an entering candidate, decreasing it never does.
+            None => (f64::INFINITY, self.matrix[(objective_row, col)]),
+
+            // Changing the coefficient of a basic variable by `delta` changes the
+            // reduced cost of every other column `k` by `delta * a_rk`.
+            Some(row) => {
+                let mut decrease = f64::INFINITY;
+                let mut increase = f64::INFINITY;
+                for k in (0..rhs_column).filter(|&k| k != col) {
+                    let a = self.matrix[(row, k)];
+                    let reduced_cost = self.matrix[(objective_row, k)];
+                    if a > self.tolerance {
+                        decrease = decrease.min(reduced_cost / a);
+                    } else if a < -self.tolerance {
+                        increase = increase.min(reduced_cost / -a);
+                    }
+                }
+                (decrease, increase)
+            }
+        };
+
+        // Rhs ranging: changing the rhs by `delta` changes the basic values by `delta * d`,
+        // where `d` is the column of the variable, and all basic values must stay non-negative.
+        let mut rhs_decrease = f64::INFINITY;
+        let mut rhs_increase = f64::INFINITY;
+        for r in 0..objective_row {
+            let d = self.matrix[(r, col)];
+            let value = self.matrix[(r, rhs_column)];
+            if d > self.tolerance {
+                rhs_decrease = rhs_decrease.min(value / d);
+            } else if d < -self.tolerance {
+                rhs_increase = rhs_increase.min(value / -d);
+            }
+        }
+
+        Some(RangingResult {
+            objective_decrease,
+            objective_increase,
+            rhs_decrease,
+            rhs_increase,
+        })
+    }
+
+    /// Perform a pivot operation on the tableau.
+    ///
+    /// # Arguments
+    /// * `pivot_row` - The index of the pivot row.
+    /// * `pivot_column` - The index of the pivot column.
+    ///
+    /// # Note
+    /// The pivot operation is performed in place using the gaussian elimination method.
+    /// A stored basis inverse is updated with `apply_eta_update`, or discarded if the pivot
+    /// is not a valid basis change.
+    pub fn gaussian_pivot(&mut self, pivot_row: usize, pivot_column: usize) {
+        let start = self.timing_enabled.then(Instant::now);
+
+        // Keep the stored basis inverse in sync with the basis.
+        if self.basis_inverse.is_some() && self.apply_eta_update(pivot_column, pivot_row).is_err() {
+            self.basis_inverse = None;
+        }
+
+        // Get the pivot element.
+        let pivot_element = self.matrix[(pivot_row, pivot_column)];
+
+        // Scale the pivot row so that the pivot element becomes 1.
+        let num_cols = self.cols();
+        for c in 0..num_cols {
+            self.matrix[(pivot_row, c)] *= 1.0 / pivot_element;
+        }
+        if let Some(perturbation) = &mut self.perturbation {
+            perturbation[pivot_row] *= 1.0 / pivot_element;
+        }
+
+        // Perform row operations to eliminate other entries in the pivot column.
+        let num_rows = self.rows();
+        for r in 0..num_rows {
+            if r != pivot_row {
+                let factor = self.matrix[(r, pivot_column)];
+                for c in 0..num_cols {
+                    self.matrix[(r, c)] -= factor * self.matrix[(pivot_row, c)];
+                }
+                if let Some(perturbation) = &mut self.perturbation {
+                    perturbation[r] -= factor * perturbation[pivot_row];
+                }
+            }
+        }
+
+        // The pivot column replaces the basic column of the pivot row.
+        if let Some(basis) = &mut self.basis {
+            basis[pivot_row] = pivot_column;
+        }
+
+        let step = PivotStep {
+            row: pivot_row,
+            column: pivot_column,
+        };
+        self.history.push(step);
+        self.pivot_count += 1;
+
+        if let Some(start) = start {
+            self.pivot_log.push(PivotLog {
+                step,
+                duration: start.elapsed(),
+            });
+        }
+    }
+}
+
+/// Implement the Display trait for Tableau.
+/// 
+/// # Note
+/// This implementation uses the tabled crate to display the tableau
+/// in a well formatted table.
+impl Display for Tableau {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut builder = tabled::builder::Builder::default();
+        
+        // Push the column headers to the table.
+        // The first cell (0, 0) is empty, because this column is used for the row names.
+        let mut column_header = vec![String::new()];
+        column_header.extend(self.column_names.iter().cloned());
+        builder.push_record(column_header);
+        
+        // Push the rows to the table.
+        // The first cell of each row is the row name.
+        for (i, row) in self.matrix.row_iter().enumerate() {
+            let mut row_data = Vec::new();
+            row_data.push(self.row_names[i].to_string());
+            row_data.extend(row.iter().map(|value| value.to_string()));
 
             builder.push_record(row_data);
         }
@@ -229,4 +2194,1150 @@ impl Display for Tableau {
         table.with(Style::markdown());
         write!(f, "{}", table)
     }
-}
\ No newline at end of file
+}
+
+/// Implement the Display trait for TableauError.
+impl Display for TableauError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TableauError::RowOutOfBounds(row) => {
+                write!(
+                    f,
+                    "The row index {} is out of the bounds of the tableau.",
+                    row
+                )
+            }
+            TableauError::ColumnOutOfBounds(col) => {
+                write!(
+                    f,
+                    "The column index {} is out of the bounds of the tableau.",
+                    col
+                )
+            }
+            TableauError::Unbounded => write!(f, "The problem is unbounded."),
+            TableauError::NoBasisFound => {
+                write!(f, "The identity columns do not form a complete basis.")
+            }
+            TableauError::DimensionMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Expected a vector of length {}, but got {}.",
+                    expected, actual
+                )
+            }
+            TableauError::SingularBasis => {
+                write!(f, "The columns of the basis are linearly dependent.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TableauError {}
+
+/// Implement the Display trait for TableauBuildError.
+impl Display for TableauBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TableauBuildError::RowNameCountMismatch { expected, actual } => {
+                write!(f, "Expected {} row names, but got {}.", expected, actual)
+            }
+            TableauBuildError::ColumnNameCountMismatch { expected, actual } => {
+                write!(f, "Expected {} column names, but got {}.", expected, actual)
+            }
+            TableauBuildError::MissingEntry { row, column } => write!(
+                f,
+                "No value was given for row {} and column {}.",
+                row, column
+            ),
+            TableauBuildError::NoVariables => {
+                write!(f, "The tableau does not reference any variable.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TableauBuildError {}
+
+/// Implement the Display trait for ConsistencyError.
+impl Display for ConsistencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ConsistencyError::RowNameCountMismatch { expected, actual } => write!(
+                f,
+                "The tableau has {} rows, but {} row names.",
+                expected, actual
+            ),
+            ConsistencyError::ColumnNameCountMismatch { expected, actual } => write!(
+                f,
+                "The tableau has {} columns, but {} column names.",
+                expected, actual
+            ),
+            ConsistencyError::BasisLengthMismatch { expected, actual } => write!(
+                f,
+                "The tableau has {} constraint rows, but a basis of length {}.",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConsistencyError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraint::{Constraint, Relation};
+    use crate::problem::{Objective, Problem};
+    use crate::tableau_builder::SimplexTableauBuilder;
+    use crate::variable::Variable;
+
+    /// The textbook problem `max 3x + 5y s.t. x <= 4, 2y <= 12, 3x + 2y <= 18`.
+    fn textbook_problem() -> Problem {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        Problem::new(
+            Objective::maximize(x.clone() * 3.0 + y.clone() * 5.0),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::LessThanOrEqual, 4.0),
+                Constraint::new((y.clone() * 2.0).into(), Relation::LessThanOrEqual, 12.0),
+                Constraint::new(x * 3.0 + y * 2.0, Relation::LessThanOrEqual, 18.0),
+            ],
+        )
+    }
+
+    fn textbook_tableau() -> Tableau {
+        SimplexTableauBuilder::from_problem(&textbook_problem())
+            .build()
+            .0
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn objective_value_aliases_and_setter() {
+        let mut tableau = textbook_tableau();
+        tableau.gaussian_pivot(1, 1);
+        tableau.gaussian_pivot(2, 0);
+        assert_eq!(tableau.objective_value(), 36.0);
+        assert_eq!(tableau.get_objective_value(), tableau.objective_value());
+
+        tableau.set_objective_value(7.5);
+        assert_eq!(tableau.objective_value(), 7.5);
+        assert_eq!(tableau.get_objective_value(), 7.5);
+    }
+
+    #[test]
+    fn renamed_rows_and_columns_are_displayed() {
+        let mut tableau = textbook_tableau();
+        tableau.set_row_name(0, "capacity".to_string()).unwrap();
+        tableau.set_column_name(0, "apples".to_string()).unwrap();
+        tableau.column_names_mut()[1] = "pears".to_string();
+
+        let output = tableau.to_string();
+        assert!(output.contains("capacity"));
+        assert!(output.contains("apples"));
+        assert!(output.contains("pears"));
+        assert_eq!(
+            tableau.set_row_name(4, "z".to_string()),
+            Err(TableauError::RowOutOfBounds(4))
+        );
+        assert_eq!(
+            tableau.set_column_name(6, "w".to_string()),
+            Err(TableauError::ColumnOutOfBounds(6))
+        );
+    }
+
+    #[test]
+    fn to_matrix2d_round_trip() {
+        let tableau = textbook_tableau();
+        let matrix = tableau.to_matrix2d();
+        assert_eq!(
+            (matrix.rows(), matrix.columns()),
+            (tableau.rows(), tableau.cols())
+        );
+        assert_eq!(matrix[(2, 0)], 3.0);
+
+        let restored = Tableau::from_matrix2d(
+            matrix,
+            tableau.row_names().clone(),
+            tableau.column_names().clone(),
+        )
+        .unwrap();
+        assert_eq!(restored.get_matrix(), tableau.get_matrix());
+    }
+
+    #[test]
+    fn from_dict_round_trip() {
+        let tableau = textbook_tableau();
+        let restored = Tableau::from_dict(tableau.export_as_dict()).unwrap();
+
+        assert_eq!(restored.row_names().last().unwrap(), "z");
+        assert_eq!(restored.column_names().last().unwrap(), "rhs");
+        for (r, row) in tableau.row_names().iter().enumerate() {
+            for (c, column) in tableau.column_names().iter().enumerate() {
+                let restored_r = restored
+                    .row_names()
+                    .iter()
+                    .position(|name| name == row)
+                    .unwrap();
+                let restored_c = restored
+                    .column_names()
+                    .iter()
+                    .position(|name| name == column)
+                    .unwrap();
+                assert_eq!(
+                    tableau.get_matrix()[(r, c)],
+                    restored.get_matrix()[(restored_r, restored_c)]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_dict_ordered_round_trip() {
+        let tableau = textbook_tableau();
+        let restored = Tableau::from_dict_ordered(
+            tableau.export_as_dict(),
+            tableau.row_names().clone(),
+            tableau.column_names().clone(),
+        )
+        .unwrap();
+        assert_eq!(restored.get_matrix(), tableau.get_matrix());
+        assert_eq!(restored.row_names(), tableau.row_names());
+        assert_eq!(restored.column_names(), tableau.column_names());
+    }
+
+    #[test]
+    fn from_dict_missing_entry() {
+        let mut dict = textbook_tableau().export_as_dict();
+        dict.remove(&("z".to_string(), "rhs".to_string()));
+        assert!(matches!(
+            Tableau::from_dict(dict),
+            Err(TableauBuildError::MissingEntry { .. })
+        ));
+    }
+
+    #[test]
+    fn export_as_dict_contains_all_entries() {
+        let tableau = textbook_tableau();
+        let dict = tableau.export_as_dict();
+        assert_eq!(dict.len(), tableau.rows() * tableau.cols());
+        assert_eq!(dict[&("z".to_string(), "x".to_string())], -3.0);
+        assert_eq!(dict[&("z".to_string(), "y".to_string())], -5.0);
+    }
+
+    #[test]
+    fn pivot_to_identity_creates_unit_column() {
+        let mut tableau = textbook_tableau();
+        let y = 1;
+        tableau.pivot_to_identity(y).unwrap();
+
+        let column = tableau.get_matrix().column(y);
+        let constraint_entries = column.rows(0, tableau.rows() - 1);
+        assert_eq!(
+            constraint_entries
+                .iter()
+                .filter(|&&value| value == 1.0)
+                .count(),
+            1
+        );
+        assert_eq!(
+            constraint_entries
+                .iter()
+                .filter(|&&value| value == 0.0)
+                .count(),
+            2
+        );
+        assert_eq!(tableau.get_matrix()[(1, y)], 1.0);
+        assert_eq!(
+            tableau.pivot_to_identity(5),
+            Err(TableauError::ColumnOutOfBounds(5))
+        );
+    }
+
+    #[test]
+    fn basis_string_of_solved_tableau() {
+        let mut tableau = textbook_tableau();
+        assert_eq!(
+            tableau.basis_string(),
+            "Row 0: s_0 = 4, Row 1: s_1 = 12, Row 2: s_2 = 18"
+        );
+
+        tableau.gaussian_pivot(1, 1);
+        tableau.gaussian_pivot(2, 0);
+        assert_eq!(
+            tableau.basis_string(),
+            "Row 0: s_0 = 2, Row 1: y = 6, Row 2: x = 2"
+        );
+    }
+
+    #[test]
+    fn solve_for_n_pivots_stops_after_n_pivots() {
+        let mut tableau = textbook_tableau();
+        assert_eq!(tableau.solve_for_n_pivots(1), SimplexResult::Interrupted(1));
+        assert_eq!(tableau.total_pivot_count(), 1);
+
+        // The count only includes the pivots of the current call.
+        assert_eq!(tableau.solve_for_n_pivots(0), SimplexResult::Interrupted(0));
+        assert_eq!(tableau.total_pivot_count(), 1);
+
+        // Solving can be resumed, the textbook problem needs one more pivot.
+        assert_eq!(tableau.solve_for_n_pivots(5), SimplexResult::Optimal);
+        assert_eq!(tableau.total_pivot_count(), 2);
+        assert_eq!(tableau.objective_value(), 36.0);
+    }
+
+    #[test]
+    fn entering_and_basic_names_partition_columns() {
+        let mut tableau = textbook_tableau();
+        tableau.solve();
+
+        let entering = tableau.entering_column_names();
+        let basic = tableau.basic_variable_names();
+        assert_eq!(entering, vec!["s_1", "s_2"]);
+        assert_eq!(basic, vec!["x", "y", "s_0"]);
+
+        let mut all: Vec<&str> = entering.into_iter().chain(basic).collect();
+        all.sort();
+        let mut expected: Vec<&str> = tableau.column_names()[..tableau.cols() - 1]
+            .iter()
+            .map(String::as_str)
+            .collect();
+        expected.sort();
+        assert_eq!(all, expected);
+    }
+
+    #[test]
+    fn deperturb_rhs_after_solve() {
+        let mut expected = textbook_tableau();
+        expected.solve();
+
+        let mut tableau = textbook_tableau();
+        tableau.anti_cycling_perturb_rhs(0.3);
+        assert_ne!(tableau.rhs_vector(), textbook_tableau().rhs_vector());
+        assert_eq!(tableau.solve(), SimplexResult::Optimal);
+        assert_eq!(tableau.total_pivot_count(), 2);
+        tableau.deperturb_rhs();
+
+        assert_eq!(tableau.basis(), expected.basis());
+        assert!((tableau.rhs_vector() - expected.rhs_vector()).amax() < 1e-9);
+        assert!((tableau.objective_value() - 36.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn deperturb_rhs_keeps_values_that_are_not_multiples_of_epsilon() {
+        let mut tableau = textbook_tableau();
+        let rhs = tableau.rhs_vector().clone_owned();
+        tableau.anti_cycling_perturb_rhs(0.3);
+        tableau.deperturb_rhs();
+        assert!((tableau.rhs_vector() - rhs).amax() < 1e-12);
+
+        // Deperturbing twice does nothing.
+        tableau.deperturb_rhs();
+        assert!((tableau.rhs_vector() - textbook_tableau().rhs_vector()).amax() < 1e-12);
+    }
+
+    #[test]
+    fn sensitivity_ranging_of_solved_problem() {
+        let mut tableau = textbook_tableau();
+        tableau.solve();
+
+        // The objective coefficient 3 of `x` can range in `[0, 7.5]`.
+        let x = tableau.sensitivity_ranging_for_variable("x").unwrap();
+        assert!((x.objective_decrease - 3.0).abs() < 1e-9);
+        assert!((x.objective_increase - 4.5).abs() < 1e-9);
+
+        // The objective coefficient 5 of `y` can range in `[2, inf)`.
+        let y = tableau.sensitivity_ranging_for_variable("y").unwrap();
+        assert!((y.objective_decrease - 3.0).abs() < 1e-9);
+        assert_eq!(y.objective_increase, f64::INFINITY);
+
+        // The rhs 12 of the second constraint can range in `[6, 18]`.
+        let s_1 = tableau.sensitivity_ranging_for_variable("s_1").unwrap();
+        assert!((s_1.rhs_decrease - 6.0).abs() < 1e-9);
+        assert!((s_1.rhs_increase - 6.0).abs() < 1e-9);
+
+        assert_eq!(tableau.sensitivity_ranging_for_variable("w"), None);
+        assert_eq!(tableau.sensitivity_ranging_for_variable("rhs"), None);
+    }
+
+    #[test]
+    fn ratio_test_results_are_sorted() {
+        let tableau = textbook_tableau();
+        let y = tableau.col_index_by_name("y").unwrap();
+        assert_eq!(tableau.ratio_test_results(y), vec![(1, 6.0), (2, 9.0)]);
+        assert_eq!(tableau.minimum_ratio_row(y), Some(1));
+
+        let x = tableau.col_index_by_name("x").unwrap();
+        assert_eq!(tableau.ratio_test_results(x), vec![(0, 4.0), (2, 6.0)]);
+    }
+
+    #[test]
+    fn tolerance_accepts_tiny_negative_values() {
+        let mut tableau = textbook_tableau();
+        tableau.solve();
+        let s_1 = tableau.col_index_by_name("s_1").unwrap();
+        tableau.set_obj_row_entry(s_1, -1e-10).unwrap();
+
+        assert_eq!(tableau.tolerance(), 1e-9);
+        assert!(tableau.is_optimal());
+        assert_eq!(tableau.entering_column(), None);
+
+        tableau.set_tolerance(0.0);
+        assert!(!tableau.is_optimal());
+        assert_eq!(tableau.entering_column(), Some(s_1));
+    }
+
+    #[test]
+    fn total_pivot_count_matches_pivots() {
+        let mut tableau = textbook_tableau();
+        assert_eq!(tableau.total_pivot_count(), 0);
+        tableau.gaussian_pivot(1, 1);
+        tableau.gaussian_pivot(2, 0);
+        assert_eq!(tableau.total_pivot_count(), 2);
+        assert_eq!(tableau.history().len(), 2);
+
+        tableau.clear_history();
+        assert!(tableau.history().is_empty());
+        assert_eq!(tableau.total_pivot_count(), 0);
+    }
+
+    #[test]
+    fn obj_row_entry_first_and_last_column() {
+        let mut tableau = textbook_tableau();
+        let last = tableau.cols() - 1;
+        assert_eq!(tableau.obj_row_entry(0), Some(-3.0));
+        assert_eq!(tableau.obj_row_entry(last), Some(0.0));
+        assert_eq!(tableau.obj_row_entry(last + 1), None);
+
+        tableau.set_obj_row_entry(0, -4.0).unwrap();
+        tableau.set_obj_row_entry(last, 10.0).unwrap();
+        assert_eq!(tableau.obj_row_entry(0), Some(-4.0));
+        assert_eq!(tableau.obj_row_entry(last), Some(10.0));
+        assert_eq!(tableau.objective_value(), 10.0);
+        assert_eq!(
+            tableau.set_obj_row_entry(last + 1, 1.0),
+            Err(TableauError::ColumnOutOfBounds(last + 1))
+        );
+    }
+
+    #[test]
+    fn pivot_log_is_populated_when_timing() {
+        let mut tableau = textbook_tableau();
+        tableau.solve();
+        assert!(tableau.pivot_log().is_empty());
+
+        let mut tableau = textbook_tableau();
+        tableau.enable_timing();
+        tableau.solve();
+        assert_eq!(tableau.pivot_log().len(), 2);
+        for (log, step) in tableau.pivot_log().iter().zip(tableau.history()) {
+            assert_eq!(&log.step, step);
+            assert!(log.duration >= Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn max_and_min_rhs_row() {
+        let tableau = textbook_tableau();
+        assert_eq!(tableau.max_rhs_row(), Some(2));
+        assert_eq!(tableau.min_rhs_row(), Some(0));
+    }
+
+    /// The dual feasible tableau of `min 2x + 3y s.t. x + y >= 2, x + 3y >= 3`,
+    /// with the constraints multiplied by -1 so that the slack variables form the basis.
+    fn dual_simplex_tableau() -> Tableau {
+        let matrix = DMatrix::from_row_slice(
+            3,
+            5,
+            &[
+                -1.0, -1.0, 1.0, 0.0, -2.0, -1.0, -3.0, 0.0, 1.0, -3.0, 2.0, 3.0, 0.0, 0.0, 0.0,
+            ],
+        );
+        let row_names = vec!["s_0".to_string(), "s_1".to_string(), "z".to_string()];
+        let column_names = ["x", "y", "s_0", "s_1", "rhs"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        Tableau::new(matrix, row_names, column_names)
+    }
+
+    #[test]
+    fn dual_ratio_test_selects_entering_column() {
+        let tableau = dual_simplex_tableau();
+        assert_eq!(tableau.min_rhs_row(), Some(1));
+        // The ratios of the second row are `2 / 1` for `x` and `3 / 3` for `y`.
+        assert_eq!(tableau.dual_ratio_test(1), Some(1));
+        // The ratios of the first row are `2 / 1` for `x` and `3 / 1` for `y`.
+        assert_eq!(tableau.dual_ratio_test(0), Some(0));
+        assert_eq!(tableau.dual_ratio_test(2), None);
+
+        // A row without negative entries has no entering column.
+        assert_eq!(textbook_tableau().dual_ratio_test(0), None);
+    }
+
+    #[test]
+    fn identify_basis_of_textbook_tableau() {
+        let tableau = textbook_tableau();
+        let mut untracked = Tableau::new(
+            tableau.get_matrix().clone(),
+            tableau.row_names().clone(),
+            tableau.column_names().clone(),
+        );
+        assert_eq!(untracked.basis(), None);
+
+        untracked.identify_basis_from_identity_columns().unwrap();
+        assert_eq!(untracked.basis(), Some(&vec![2, 3, 4]));
+    }
+
+    #[test]
+    fn identify_basis_without_identity_columns() {
+        let matrix = DMatrix::from_row_slice(2, 3, &[2.0, 2.0, 3.0, 0.0, 0.0, 0.0]);
+        let row_names = vec!["c0".to_string(), "z".to_string()];
+        let column_names = vec!["x".to_string(), "y".to_string(), "rhs".to_string()];
+        let mut tableau = Tableau::new(matrix, row_names, column_names);
+
+        assert_eq!(
+            tableau.identify_basis_from_identity_columns(),
+            Err(TableauError::NoBasisFound)
+        );
+        assert_eq!(tableau.basis(), None);
+    }
+
+    #[test]
+    fn objective_coefficient_for_known_and_absent_variable() {
+        let tableau = textbook_tableau();
+        assert_eq!(tableau.objective_coefficient_for_variable("x"), Some(-3.0));
+        assert_eq!(tableau.objective_coefficient_for_variable("y"), Some(-5.0));
+        assert_eq!(tableau.objective_coefficient_for_variable("s_0"), Some(0.0));
+        assert_eq!(tableau.objective_coefficient_for_variable("w"), None);
+    }
+
+    #[test]
+    fn lhs_matrix_excludes_rhs_column() {
+        let tableau = textbook_tableau();
+        let lhs = tableau.lhs_matrix();
+        assert_eq!(lhs.nrows(), tableau.rows());
+        assert_eq!(lhs.ncols(), tableau.cols() - 1);
+        assert_eq!(lhs[(2, 0)], 3.0);
+        assert_eq!(lhs[(3, 1)], -5.0);
+    }
+
+    #[test]
+    fn row_as_expression_of_constraint_row() {
+        let tableau = textbook_tableau();
+        let expression = tableau.row_as_expression(2).unwrap();
+        assert_eq!(expression.terms().len(), 3);
+        assert_eq!(expression.coefficient(&Variable::new("x")), 3.0);
+        assert_eq!(expression.coefficient(&Variable::new("y")), 2.0);
+        assert_eq!(expression.coefficient(&Variable::new("s_2")), 1.0);
+        assert_eq!(expression.coefficient(&Variable::new("rhs")), 0.0);
+        assert!(tableau.row_as_expression(tableau.rows()).is_none());
+    }
+
+    #[test]
+    fn objective_as_expression_of_solved_tableau() {
+        let mut tableau = textbook_tableau();
+        tableau.solve();
+        let expression = tableau.objective_as_expression();
+        assert_eq!(expression.terms().len(), 2);
+        assert!((expression.coefficient(&Variable::new("s_1")) - 1.5).abs() < 1e-9);
+        assert!((expression.coefficient(&Variable::new("s_2")) - 1.0).abs() < 1e-9);
+        assert_eq!(expression.coefficient(&Variable::new("x")), 0.0);
+        assert_eq!(expression.coefficient(&Variable::new("y")), 0.0);
+    }
+
+    #[test]
+    fn column_count_active_and_constraint_count() {
+        let tableau = textbook_tableau();
+        assert_eq!(tableau.column_count_active(), 5);
+        assert_eq!(tableau.column_count_active(), tableau.cols() - 1);
+        assert_eq!(tableau.constraint_count(), 3);
+        assert_eq!(tableau.constraint_count(), tableau.rows() - 1);
+    }
+
+    #[test]
+    fn is_identity_matrix_before_and_after_pivot() {
+        let mut tableau = textbook_tableau();
+        assert!(tableau.is_identity_matrix());
+
+        tableau.gaussian_pivot(1, 1);
+        assert!(!tableau.is_identity_matrix());
+    }
+
+    #[test]
+    fn refactorize_matches_fresh_tableau() {
+        let mut solved = textbook_tableau();
+        solved.solve();
+        let pivoted = solved.get_matrix().clone();
+
+        solved.refactorize().unwrap();
+        assert!((solved.get_matrix() - &pivoted).amax() < 1e-9);
+
+        let mut fresh = textbook_tableau();
+        fresh
+            .refactorize_with_basis(solved.basis().unwrap().clone())
+            .unwrap();
+        assert!((fresh.get_matrix() - &pivoted).amax() < 1e-9);
+        assert!((fresh.objective_value() - 36.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn refactorize_without_basis() {
+        let tableau = textbook_tableau();
+        let mut untracked = Tableau::new(
+            tableau.get_matrix().clone(),
+            tableau.row_names().clone(),
+            tableau.column_names().clone(),
+        );
+        assert_eq!(untracked.refactorize(), Err(TableauError::NoBasisFound));
+    }
+
+    #[test]
+    fn column_activity_of_basic_column_is_unit_vector() {
+        let mut tableau = textbook_tableau();
+        tableau.solve();
+        for (r, &col) in tableau.basis().unwrap().iter().enumerate() {
+            let activity = tableau.column_activity(col).unwrap();
+            assert_eq!(activity.len(), tableau.rows() - 1);
+            for (i, value) in activity.iter().enumerate() {
+                let expected = if i == r { 1.0 } else { 0.0 };
+                assert!((value - expected).abs() < 1e-9);
+            }
+        }
+        assert_eq!(tableau.column_activity(tableau.cols() - 1), None);
+    }
+
+    #[test]
+    fn update_objective_row_prices_out_basis() {
+        let mut tableau = textbook_tableau();
+        tableau.solve();
+
+        // max x + y at the basis of the textbook optimum, x = 2 and y = 6.
+        tableau
+            .update_objective_row(&[-1.0, -1.0, 0.0, 0.0, 0.0])
+            .unwrap();
+        let expected = [0.0, 0.0, 0.0, 1.0 / 6.0, 1.0 / 3.0, 8.0];
+        for (c, value) in expected.iter().enumerate() {
+            assert!((tableau.obj_row_entry(c).unwrap() - value).abs() < 1e-9);
+        }
+        assert!((tableau.objective_value() - 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn update_objective_row_errors() {
+        let mut tableau = textbook_tableau();
+        assert_eq!(
+            tableau.update_objective_row(&[-1.0, -1.0]),
+            Err(TableauError::DimensionMismatch {
+                expected: 5,
+                actual: 2
+            })
+        );
+
+        let mut untracked = Tableau::new(
+            tableau.get_matrix().clone(),
+            tableau.row_names().clone(),
+            tableau.column_names().clone(),
+        );
+        assert_eq!(
+            untracked.update_objective_row(&[0.0; 5]),
+            Err(TableauError::NoBasisFound)
+        );
+    }
+
+    #[test]
+    fn find_pivot_bland_combines_entering_and_leaving() {
+        let tableau = textbook_tableau();
+        assert_eq!(tableau.bland_entering_column(), Some(0));
+        assert_eq!(tableau.bland_leaving_row(0), Some(0));
+        assert_eq!(tableau.find_pivot_bland(), Some((0, 0)));
+
+        let mut solved = textbook_tableau();
+        solved.solve();
+        assert_eq!(solved.find_pivot_bland(), None);
+    }
+
+    #[test]
+    fn nonnegative_basis_values_and_negative_rows() {
+        let tableau = textbook_tableau();
+        assert!(tableau.nonnegative_basis_values());
+        assert!(tableau.negative_basis_row_indices().is_empty());
+
+        let tableau = dual_simplex_tableau();
+        assert!(!tableau.nonnegative_basis_values());
+        assert_eq!(tableau.negative_basis_row_indices(), vec![0, 1]);
+    }
+
+    #[test]
+    fn canonical_pivot_column_threshold_skips_small_reduced_costs() {
+        let tableau = textbook_tableau();
+        assert_eq!(tableau.canonical_pivot_column_threshold(1.0), Some(1));
+        assert_eq!(tableau.canonical_pivot_column_threshold(5.0), None);
+        assert_eq!(tableau.canonical_pivot_column_threshold(10.0), None);
+    }
+
+    #[test]
+    fn feasibility_distance_of_feasible_and_infeasible_tableau() {
+        assert_eq!(textbook_tableau().feasibility_distance(), 0.0);
+        // The rhs entries of the constraint rows are -2 and -3.
+        assert_eq!(dual_simplex_tableau().feasibility_distance(), 5.0);
+    }
+
+    #[test]
+    fn optimality_distance_before_and_after_solve() {
+        let mut tableau = textbook_tableau();
+        // The objective coefficients of x and y are -3 and -5.
+        assert_eq!(tableau.optimality_distance(), 8.0);
+
+        tableau.solve();
+        assert_eq!(tableau.optimality_distance(), 0.0);
+    }
+
+    #[test]
+    fn add_constraint_row_lands_above_objective_row() {
+        let mut tableau = textbook_tableau();
+        tableau
+            .add_constraint_row(
+                "cut".to_string(),
+                DVector::from_vec(vec![1.0, 1.0, 0.0, 0.0, 0.0]),
+                7.0,
+            )
+            .unwrap();
+
+        assert_eq!(tableau.rows(), 5);
+        assert_eq!(tableau.row_names()[3], "cut");
+        assert_eq!(tableau.row_names()[4], "z");
+        assert_eq!(
+            tableau
+                .get_matrix()
+                .row(3)
+                .iter()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![1.0, 1.0, 0.0, 0.0, 0.0, 7.0]
+        );
+        assert_eq!(tableau.obj_row_entry(1), Some(-5.0));
+        // No column of the new row has identity structure.
+        assert_eq!(tableau.basis(), None);
+    }
+
+    #[test]
+    fn add_constraint_row_dimension_mismatch() {
+        let mut tableau = textbook_tableau();
+        assert_eq!(
+            tableau.add_constraint_row("cut".to_string(), DVector::from_vec(vec![1.0, 1.0]), 7.0),
+            Err(TableauError::DimensionMismatch {
+                expected: 5,
+                actual: 2
+            })
+        );
+        assert_eq!(tableau.rows(), 4);
+    }
+
+    #[test]
+    fn set_objective_row_index_swaps_rows_and_names() {
+        let mut swapped = textbook_tableau();
+        let tableau = textbook_tableau();
+        swapped.set_objective_row_index(0).unwrap();
+
+        assert_eq!(swapped.row_names()[0], "z");
+        assert_eq!(swapped.row_names()[3], tableau.row_names()[0]);
+        assert_eq!(swapped.get_matrix().row(0), tableau.get_matrix().row(3));
+        assert_eq!(swapped.get_matrix().row(3), tableau.get_matrix().row(0));
+        assert_eq!(swapped.basis(), None);
+
+        swapped.set_objective_row_index(0).unwrap();
+        assert_eq!(swapped.get_matrix(), tableau.get_matrix());
+        assert_eq!(swapped.row_names(), tableau.row_names());
+        assert_eq!(
+            swapped.set_objective_row_index(4),
+            Err(TableauError::RowOutOfBounds(4))
+        );
+    }
+
+    #[test]
+    fn slack_tracking_maps_inequalities() {
+        let x = Variable::new("x");
+        let problem = Problem::new(
+            Objective::maximize((x.clone() * 1.0).into()),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::LessThanOrEqual, 4.0),
+                Constraint::new((x.clone() * 1.0).into(), Relation::Equal, 2.0),
+                Constraint::new((x * 1.0).into(), Relation::GreaterThanOrEqual, 1.0),
+            ],
+        );
+        let (tableau, slacks) = Tableau::from_problem_with_slack_tracking(&problem);
+
+        assert_eq!(slacks.len(), 2);
+        assert_eq!(slacks["c0"], 1);
+        assert_eq!(slacks["c2"], 2);
+        assert!(!slacks.contains_key("c1"));
+        assert_eq!(tableau.get_matrix()[(0, slacks["c0"])], 1.0);
+        assert_eq!(tableau.get_matrix()[(2, slacks["c2"])], -1.0);
+    }
+
+    #[test]
+    fn slack_tracking_ignores_user_variables_named_like_slacks() {
+        let s = Variable::family("s", 2);
+        let problem = Problem::new(
+            Objective::maximize(s[0].clone() * 1.0 + s[1].clone() * 1.0),
+            vec![
+                Constraint::new((s[0].clone() * 1.0).into(), Relation::LessThanOrEqual, 1.0),
+                Constraint::new((s[1].clone() * 1.0).into(), Relation::LessThanOrEqual, 1.0),
+            ],
+        );
+        let (_, slacks) = Tableau::from_problem_with_slack_tracking(&problem);
+        assert_eq!(slacks["c0"], 2);
+        assert_eq!(slacks["c1"], 3);
+    }
+
+    #[test]
+    fn pivot_element_and_is_valid_pivot() {
+        let tableau = textbook_tableau();
+        assert_eq!(tableau.pivot_element(2, 0), Some(3.0));
+        assert_eq!(tableau.pivot_element(1, 0), Some(0.0));
+        assert_eq!(tableau.pivot_element(4, 0), None);
+
+        assert!(tableau.is_valid_pivot(2, 0, 1e-9));
+        assert!(!tableau.is_valid_pivot(1, 0, 1e-9));
+        assert!(!tableau.is_valid_pivot(0, 6, 1e-9));
+    }
+
+    #[test]
+    fn max_absolute_entry_and_frobenius_norm() {
+        let tableau = textbook_tableau();
+        assert_eq!(tableau.max_absolute_entry(), 18.0);
+        // The squares of the entries sum up to 18 + 149 + 338 + 34.
+        assert!((tableau.frobenius_norm() - 539.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn pivot_with_strategy_matches_gaussian_pivot() {
+        let mut tableau = textbook_tableau();
+        let mut expected = textbook_tableau();
+        expected.gaussian_pivot(0, 0);
+
+        assert_eq!(
+            tableau.pivot_with_strategy(|_| Some((0, 0))),
+            PivotOutcome::Pivoted(PivotStep { row: 0, column: 0 })
+        );
+        assert_eq!(tableau.get_matrix(), expected.get_matrix());
+        assert_eq!(tableau.basis(), expected.basis());
+    }
+
+    #[test]
+    fn pivot_with_strategy_rejects_invalid_pivots() {
+        let mut tableau = textbook_tableau();
+        let matrix = tableau.get_matrix().clone();
+
+        assert_eq!(tableau.pivot_with_strategy(|_| None), PivotOutcome::NoPivot);
+        // The objective row, the rhs column and a zero element cannot be pivoted on.
+        assert_eq!(
+            tableau.pivot_with_strategy(|_| Some((3, 0))),
+            PivotOutcome::InvalidPivot(PivotStep { row: 3, column: 0 })
+        );
+        assert_eq!(
+            tableau.pivot_with_strategy(|_| Some((0, 5))),
+            PivotOutcome::InvalidPivot(PivotStep { row: 0, column: 5 })
+        );
+        assert_eq!(
+            tableau.pivot_with_strategy(|_| Some((1, 0))),
+            PivotOutcome::InvalidPivot(PivotStep { row: 1, column: 0 })
+        );
+        assert_eq!(tableau.get_matrix(), &matrix);
+        assert_eq!(tableau.total_pivot_count(), 0);
+    }
+
+    #[test]
+    fn from_equations_tracks_identity_basis() {
+        let [x, y, s_0, s_1, s_2] = ["x", "y", "s_0", "s_1", "s_2"].map(Variable::new);
+        let equations = [
+            (x.clone() * 1.0 + s_0 * 1.0, 4.0),
+            (y.clone() * 2.0 + s_1 * 1.0, 12.0),
+            (x.clone() * 3.0 + y.clone() * 2.0 + s_2 * 1.0, 18.0),
+        ];
+        let tableau = Tableau::from_equations(&equations, x * -3.0 + y * -5.0).unwrap();
+
+        // The columns are ordered by the first occurrence of the variables.
+        let column_names: Vec<String> = ["x", "s_0", "y", "s_1", "s_2", "rhs"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        assert_eq!(tableau.column_names(), &column_names);
+        assert_eq!(
+            tableau.row_names(),
+            &vec![
+                "c0".to_string(),
+                "c1".to_string(),
+                "c2".to_string(),
+                "z".to_string()
+            ]
+        );
+        let expected = textbook_tableau();
+        for (c, name) in column_names.iter().enumerate() {
+            let expected_c = expected.col_index_by_name(name).unwrap();
+            assert_eq!(
+                tableau.get_matrix().column(c),
+                expected.get_matrix().column(expected_c)
+            );
+        }
+        assert_eq!(tableau.basis(), Some(&vec![1, 3, 4]));
+    }
+
+    #[test]
+    fn from_equations_without_identity_basis() {
+        let [x, y, z] = ["x", "y", "z"].map(Variable::new);
+        let equations = [
+            (x.clone() * 2.0 + y.clone() * 1.0 + z.clone() * 1.0, 5.0),
+            (x.clone() * 1.0 + y.clone() * 3.0 + z.clone() * 2.0, 10.0),
+            (x.clone() * 1.0 + y.clone() * 1.0 + z.clone() * 4.0, 8.0),
+        ];
+        let tableau = Tableau::from_equations(&equations, x * 1.0 + y * 1.0 + z * 1.0).unwrap();
+
+        assert_eq!((tableau.rows(), tableau.cols()), (4, 4));
+        assert_eq!(
+            tableau.rhs_vector().iter().copied().collect::<Vec<f64>>(),
+            vec![5.0, 10.0, 8.0]
+        );
+        assert_eq!(tableau.pivot_element(1, 1), Some(3.0));
+        assert_eq!(tableau.basis(), None);
+    }
+
+    #[test]
+    fn from_equations_without_variables() {
+        let result = Tableau::from_equations(
+            &[(LinearExpression::new(Vec::new()), 1.0)],
+            LinearExpression::new(Vec::new()),
+        );
+        assert!(matches!(result, Err(TableauBuildError::NoVariables)));
+    }
+
+    #[test]
+    fn make_column_basic_creates_identity_column() {
+        let mut tableau = textbook_tableau();
+        // The ratios of y are 12 / 2 in row 1 and 18 / 2 in row 2.
+        assert_eq!(tableau.make_column_basic(1), Ok(1));
+        for r in 0..tableau.rows() {
+            assert_eq!(
+                tableau.pivot_element(r, 1),
+                Some(if r == 1 { 1.0 } else { 0.0 })
+            );
+        }
+        assert_eq!(tableau.basic_column(1), Some(1));
+        assert_eq!(tableau.basis(), Some(&vec![2, 1, 4]));
+
+        assert_eq!(
+            tableau.make_column_basic(5),
+            Err(TableauError::ColumnOutOfBounds(5))
+        );
+    }
+
+    /// The textbook tableau with an additional column `w` that is zero in all constraint rows.
+    fn tableau_with_zero_column() -> (Tableau, usize) {
+        let mut tableau = textbook_tableau();
+        tableau
+            .add_column(
+                "w".to_string(),
+                DVector::from_vec(vec![0.0, 0.0, 0.0, -1.0]),
+            )
+            .unwrap();
+        let col = tableau.col_index_by_name("w").unwrap();
+        (tableau, col)
+    }
+
+    #[test]
+    fn zero_column_detection() {
+        let (tableau, w) = tableau_with_zero_column();
+        assert!(tableau.column_is_zero_in_constraint_rows(w));
+        assert!(tableau.non_zero_constraint_entries(w).is_empty());
+
+        let x = tableau.col_index_by_name("x").unwrap();
+        assert!(!tableau.column_is_zero_in_constraint_rows(x));
+        assert_eq!(tableau.non_zero_constraint_entries(x), vec![0, 2]);
+    }
+
+    #[test]
+    fn zero_column_detection_ignores_rhs_and_out_of_bounds_columns() {
+        let (tableau, _) = tableau_with_zero_column();
+        let rhs = tableau.cols() - 1;
+        assert!(!tableau.column_is_zero_in_constraint_rows(rhs));
+        assert!(tableau.non_zero_constraint_entries(rhs).is_empty());
+
+        let out_of_bounds = tableau.cols();
+        assert!(!tableau.column_is_zero_in_constraint_rows(out_of_bounds));
+        assert!(tableau
+            .non_zero_constraint_entries(out_of_bounds)
+            .is_empty());
+    }
+
+    #[test]
+    fn zero_column_is_not_pivoted() {
+        let (mut tableau, w) = tableau_with_zero_column();
+        let matrix = tableau.get_matrix().clone();
+
+        assert_eq!(tableau.make_column_basic(w), Err(TableauError::Unbounded));
+        assert_eq!(
+            tableau.pivot_with_strategy(|_| Some((0, w))),
+            PivotOutcome::InvalidPivot(PivotStep { row: 0, column: w })
+        );
+        assert_eq!(tableau.get_matrix(), &matrix);
+        assert_eq!(tableau.total_pivot_count(), 0);
+    }
+
+    #[test]
+    fn eta_update_matches_recomputed_inverse() {
+        let mut tableau = textbook_tableau();
+        tableau.compute_basis_inverse().unwrap();
+
+        // Let `y` replace the slack of the second constraint.
+        let y = tableau.col_index_by_name("y").unwrap();
+        tableau.apply_eta_update(y, 1).unwrap();
+        let updated = tableau.basis_inverse().unwrap().clone();
+
+        let mut basis = tableau.basis().unwrap().clone();
+        basis[1] = y;
+        let expected = tableau.basis_matrix(&basis).try_inverse().unwrap();
+        assert!((updated - expected).amax() < 1e-12);
+    }
+
+    #[test]
+    fn eta_update_requires_stored_inverse() {
+        let mut tableau = textbook_tableau();
+        assert_eq!(
+            tableau.apply_eta_update(0, 0),
+            Err(TableauError::NoBasisFound)
+        );
+        tableau.compute_basis_inverse().unwrap();
+        assert_eq!(
+            tableau.apply_eta_update(0, 3),
+            Err(TableauError::RowOutOfBounds(3))
+        );
+    }
+
+    #[test]
+    fn pivots_keep_basis_inverse_in_sync() {
+        let mut tableau = textbook_tableau();
+        tableau.compute_basis_inverse().unwrap();
+        assert_eq!(tableau.solve(), SimplexResult::Optimal);
+
+        let updated = tableau.basis_inverse().unwrap().clone();
+        tableau.compute_basis_inverse().unwrap();
+        assert!((updated - tableau.basis_inverse().unwrap()).amax() < 1e-9);
+    }
+
+    #[test]
+    fn is_unbounded_direction_of_unbounded_column() {
+        // max x + y s.t. x - y <= 1 is unbounded in the direction of y.
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let problem = Problem::new(
+            Objective::maximize(x.clone() * 1.0 + y.clone() * 1.0),
+            vec![Constraint::new(
+                x * 1.0 + y * -1.0,
+                Relation::LessThanOrEqual,
+                1.0,
+            )],
+        );
+        let mut tableau = SimplexTableauBuilder::from_problem(&problem).build().0;
+
+        assert!(!tableau.is_unbounded_direction(0));
+        assert!(tableau.is_unbounded_direction(1));
+        assert!(!tableau.is_unbounded_direction(tableau.cols() - 1));
+        assert_eq!(tableau.solve(), SimplexResult::Unbounded);
+    }
+
+    #[test]
+    fn is_unbounded_direction_of_bounded_tableau() {
+        let tableau = textbook_tableau();
+        assert!((0..tableau.cols() - 1).all(|col| !tableau.is_unbounded_direction(col)));
+    }
+
+    #[test]
+    fn dual_objective_value_equals_objective_value_at_optimum() {
+        let mut tableau = textbook_tableau();
+        assert_eq!(tableau.dual_objective_value(), 0.0);
+
+        tableau.solve();
+        assert!((tableau.objective_value() - 36.0).abs() < 1e-9);
+        assert!((tableau.dual_objective_value() - tableau.objective_value()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn is_phase1_complete_after_solving_phase1() {
+        let x = Variable::new("x");
+        let problem = Problem::new(
+            Objective::maximize((x.clone() * 1.0).into()),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::GreaterThanOrEqual, 1.0),
+                Constraint::new((x * 1.0).into(), Relation::LessThanOrEqual, 3.0),
+            ],
+        );
+        let (mut tableau, artificial_info) =
+            SimplexTableauBuilder::from_problem(&problem).build_phase1();
+        let artificial_cols = artificial_info.columns().clone();
+
+        // The artificial variable of `x >= 1` starts in the basis with the value 1.
+        assert_eq!(tableau.is_phase1_complete(&artificial_cols), Ok(false));
+
+        assert_eq!(tableau.solve(), SimplexResult::Optimal);
+        assert_eq!(tableau.is_phase1_complete(&artificial_cols), Ok(true));
+    }
+
+    #[test]
+    fn is_phase1_complete_rejects_invalid_columns() {
+        let tableau = textbook_tableau();
+        let rhs = tableau.cols() - 1;
+        assert_eq!(
+            tableau.is_phase1_complete(&[0, rhs]),
+            Err(TableauError::ColumnOutOfBounds(rhs))
+        );
+        assert_eq!(
+            tableau.is_phase1_complete(&[7]),
+            Err(TableauError::ColumnOutOfBounds(7))
+        );
+    }
+
+    #[test]
+    fn verify_consistency_of_consistent_tableau() {
+        assert_eq!(textbook_tableau().verify_consistency(), Ok(()));
+    }
+
+    #[test]
+    fn verify_consistency_detects_row_name_count_mismatch() {
+        let mut tableau = textbook_tableau();
+        tableau.row_names_mut().pop();
+        assert_eq!(
+            tableau.verify_consistency(),
+            Err(ConsistencyError::RowNameCountMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn verify_consistency_detects_column_name_count_mismatch() {
+        let mut tableau = textbook_tableau();
+        tableau.column_names_mut().push("w".to_string());
+        assert_eq!(
+            tableau.verify_consistency(),
+            Err(ConsistencyError::ColumnNameCountMismatch {
+                expected: 6,
+                actual: 7
+            })
+        );
+    }
+
+    #[test]
+    fn verify_consistency_detects_basis_length_mismatch() {
+        let mut tableau = textbook_tableau();
+        tableau.set_basis(vec![2, 3]);
+        assert_eq!(
+            tableau.verify_consistency(),
+            Err(ConsistencyError::BasisLengthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+use nalgebra::DMatrix;
+
+use crate::constraint::Relation;
+use crate::problem::{ObjectiveType, Problem};
+use crate::tableau::Tableau;
+
+/// Information about the artificial variables of a tableau.
+pub struct ArtificialInfo {
+    /// The indices of the columns of the artificial variables.
+    columns: Vec<usize>,
+
+    /// Whether phase 1 of the two phase simplex method is required.
+    phase1_required: bool,
+}
+
+impl ArtificialInfo {
+    /// Get the indices of the columns of the artificial variables.
+    ///
+    /// # Returns
+    /// The indices of the columns of the artificial variables.
+    pub fn columns(&self) -> &Vec<usize> {
+        &self.columns
+    }
+
+    /// Check if phase 1 of the two phase simplex method is required.
+    ///
+    /// # Returns
+    /// - `true` if the tableau contains artificial variables.
+    /// - `false` if the slack variables form a feasible initial basis.
+    pub fn is_phase1_required(&self) -> bool {
+        self.phase1_required
+    }
+}
+
+/// A builder that constructs the initial simplex tableau of a problem.
+///
+/// # Note
+/// The columns of the tableau are ordered as follows:
+/// the variables of the problem, the slack and surplus variables,
+/// the artificial variables and finally the rhs column.
+/// The last row of the tableau is the objective row.
+pub struct SimplexTableauBuilder {
+    /// The matrix of the tableau.
+    matrix: DMatrix<f64>,
+
+    /// The names of the rows of the tableau.
+    row_names: Vec<String>,
+
+    /// The names of the columns of the tableau.
+    column_names: Vec<String>,
+
+    /// The indices of the columns of the artificial variables.
+    artificial_columns: Vec<usize>,
+
+    /// The index of the column of the slack or surplus variable of each constraint.
+    slack_columns: Vec<Option<usize>>,
+
+    /// The index of the basic column of each constraint row.
+    basis: Vec<usize>,
+}
+
+impl SimplexTableauBuilder {
+    /// Create a new SimplexTableauBuilder from a problem.
+    ///
+    /// # Arguments
+    /// * `problem` - The problem to build the tableau for.
+    ///
+    /// # Returns
+    /// A new SimplexTableauBuilder.
+    ///
+    /// # Note
+    /// Constraints with a negative rhs are multiplied by -1 first.
+    /// Afterwards every `<=` constraint gets a slack variable,
+    /// every `>=` constraint gets a surplus and an artificial variable,
+    /// and every `=` constraint gets an artificial variable.
+    /// The slack and artificial variables are named `s_{r}` and `a_{r}` after their row `r`,
+    /// with underscores appended if the problem already has a variable of that name.
+    pub fn from_problem(problem: &Problem) -> SimplexTableauBuilder {
+        let variables = problem.variables();
+        let constraints = problem.constraints();
+
+        // Normalize the constraints so that all rhs values are non-negative.
+        let normalized: Vec<(f64, Relation)> = constraints
+            .iter()
+            .map(|constraint| {
+                if constraint.rhs() < 0.0 {
+                    (-1.0, constraint.relation().reversed())
+                } else {
+                    (1.0, constraint.relation())
+                }
+            })
+            .collect();
+
+        // Count the additional variables.
+        let num_slacks = normalized
+            .iter()
+            .filter(|(_, relation)| *relation != Relation::Equal)
+            .count();
+        let num_artificials = normalized
+            .iter()
+            .filter(|(_, relation)| *relation != Relation::LessThanOrEqual)
+            .count();
+
+        let num_rows = constraints.len() + 1;
+        let num_cols = variables.len() + num_slacks + num_artificials + 1;
+        let mut matrix = DMatrix::zeros(num_rows, num_cols);
+
+        let mut column_names: Vec<String> =
+            variables.iter().map(|v| v.name().to_string()).collect();
+        let mut row_names = Vec::with_capacity(num_rows);
+        let mut artificial_columns = Vec::with_capacity(num_artificials);
+        let mut slack_columns = Vec::with_capacity(constraints.len());
+        let mut basis = Vec::with_capacity(constraints.len());
+
+        let mut slack_col = variables.len();
+        let mut artificial_col = variables.len() + num_slacks;
+        let mut artificial_names = Vec::with_capacity(num_artificials);
+        let mut taken_names = variables.clone();
+
+        for (r, (constraint, (sign, relation))) in constraints.iter().zip(normalized).enumerate() {
+            // Fill in the coefficients of the variables and the rhs.
+            for (c, variable) in variables.iter().enumerate() {
+                matrix[(r, c)] = sign * constraint.expression().coefficient(variable);
+            }
+            matrix[(r, num_cols - 1)] = sign * constraint.rhs();
+
+            // Add the slack or surplus variable.
+            if relation == Relation::Equal {
+                slack_columns.push(None);
+            } else {
+                let variable = Problem::unused_variable(format!("s_{}", r), &taken_names);
+                let name = variable.name().to_string();
+                taken_names.push(variable);
+                matrix[(r, slack_col)] = if relation == Relation::LessThanOrEqual {
+                    1.0
+                } else {
+                    -1.0
+                };
+                column_names.push(name.clone());
+                slack_columns.push(Some(slack_col));
+
+                // The slack variable of a <= constraint is part of the initial basis.
+                if relation == Relation::LessThanOrEqual {
+                    row_names.push(name);
+                    basis.push(slack_col);
+                    slack_col += 1;
+                    continue;
+                }
+                slack_col += 1;
+            }
+
+            // Add the artificial variable, which is part of the initial basis.
+            let variable = Problem::unused_variable(format!("a_{}", r), &taken_names);
+            let name = variable.name().to_string();
+            taken_names.push(variable);
+            matrix[(r, artificial_col)] = 1.0;
+            artificial_names.push(name.clone());
+            artificial_columns.push(artificial_col);
+            row_names.push(name);
+            basis.push(artificial_col);
+            artificial_col += 1;
+        }
+        column_names.extend(artificial_names);
+        column_names.push("rhs".to_string());
+
+        // Set up the objective row. A minimization is turned into a maximization.
+        let objective = problem.objective();
+        let sign = match objective.objective_type() {
+            ObjectiveType::Maximize => -1.0,
+            ObjectiveType::Minimize => 1.0,
+        };
+        for (c, variable) in variables.iter().enumerate() {
+            matrix[(num_rows - 1, c)] = sign * objective.expression().coefficient(variable);
+        }
+        row_names.push("z".to_string());
+
+        SimplexTableauBuilder {
+            matrix,
+            row_names,
+            column_names,
+            artificial_columns,
+            slack_columns,
+            basis,
+        }
+    }
+
+    /// Get the indices of the columns of the slack and surplus variables.
+    ///
+    /// # Returns
+    /// The index of the column of the slack or surplus variable of each constraint,
+    /// or `None` for equality constraints, which have no slack or surplus variable.
+    pub fn slack_columns(&self) -> &Vec<Option<usize>> {
+        &self.slack_columns
+    }
+
+    /// Build the tableau.
+    ///
+    /// # Returns
+    /// The initial tableau and the information about its artificial variables.
+    pub fn build(self) -> (Tableau, ArtificialInfo) {
+        let phase1_required = !self.artificial_columns.is_empty();
+        let mut tableau = Tableau::new(self.matrix, self.row_names, self.column_names);
+        tableau.set_basis(self.basis);
+        let artificial_info = ArtificialInfo {
+            columns: self.artificial_columns,
+            phase1_required,
+        };
+        (tableau, artificial_info)
+    }
+
+    /// Build the tableau for phase 1 of the two phase simplex method.
+    ///
+    /// # Returns
+    /// The initial phase 1 tableau and the information about its artificial variables.
+    ///
+    /// # Note
+    /// The objective row is replaced by the phase 1 objective, which minimizes the
+    /// sum of the artificial variables. The objective row is already expressed in terms
+    /// of the non-basic variables, so the tableau can be solved right away.
+    /// The problem is feasible if and only if the optimal phase 1 objective value is zero.
+    pub fn build_phase1(mut self) -> (Tableau, ArtificialInfo) {
+        let objective_row = self.matrix.nrows() - 1;
+        self.matrix.row_mut(objective_row).fill(0.0);
+
+        // Minimizing the sum of the artificial variables is maximizing its negation.
+        for &col in &self.artificial_columns {
+            self.matrix[(objective_row, col)] = 1.0;
+        }
+
+        // Eliminate the artificial variables from the objective row,
+        // since they are part of the initial basis.
+        for (r, &col) in self.basis.iter().enumerate() {
+            if self.artificial_columns.contains(&col) {
+                let row = self.matrix.row(r).clone_owned();
+                let mut objective = self.matrix.row_mut(objective_row);
+                objective -= row;
+            }
+        }
+
+        self.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraint::Constraint;
+    use crate::problem::Objective;
+    use crate::variable::Variable;
+
+    #[test]
+    fn build_has_one_row_per_constraint_and_one_column_per_variable() {
+        let x = Variable::new("x");
+        let y = Variable::new("y");
+        let problem = Problem::new(
+            Objective::maximize(x.clone() * 3.0 + y.clone() * 5.0),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::LessThanOrEqual, 4.0),
+                Constraint::new((y.clone() * 2.0).into(), Relation::LessThanOrEqual, 12.0),
+                Constraint::new(x * 3.0 + y * 2.0, Relation::LessThanOrEqual, 18.0),
+            ],
+        );
+        let (tableau, artificial_info) = SimplexTableauBuilder::from_problem(&problem).build();
+
+        // Three constraint rows and the objective row.
+        assert_eq!(tableau.rows(), 4);
+        // Two variables, three slack variables and the rhs column.
+        assert_eq!(tableau.cols(), 6);
+        assert!(!artificial_info.is_phase1_required());
+        assert_eq!(tableau.basis(), Some(&vec![2, 3, 4]));
+    }
+
+    #[test]
+    fn build_adds_artificial_columns() {
+        let x = Variable::new("x");
+        let problem = Problem::new(
+            Objective::minimize((x.clone() * 1.0).into()),
+            vec![
+                Constraint::new((x.clone() * 1.0).into(), Relation::GreaterThanOrEqual, 1.0),
+                Constraint::new((x * 1.0).into(), Relation::Equal, 2.0),
+            ],
+        );
+        let (tableau, artificial_info) = SimplexTableauBuilder::from_problem(&problem).build();
+
+        // One variable, one surplus variable, two artificial variables and the rhs column.
+        assert_eq!(tableau.rows(), 3);
+        assert_eq!(tableau.cols(), 5);
+        assert!(artificial_info.is_phase1_required());
+        assert_eq!(artificial_info.columns(), &vec![2, 3]);
+    }
+
+    #[test]
+    fn build_avoids_names_of_problem_variables() {
+        let s_0 = Variable::new("s_0");
+        let a_1 = Variable::new("a_1");
+        let problem = Problem::new(
+            Objective::maximize(s_0.clone() * 1.0 + a_1.clone() * 1.0),
+            vec![
+                Constraint::new((s_0 * 1.0).into(), Relation::LessThanOrEqual, 1.0),
+                Constraint::new((a_1 * 1.0).into(), Relation::Equal, 1.0),
+            ],
+        );
+        let (tableau, _) = SimplexTableauBuilder::from_problem(&problem).build();
+
+        let column_names: Vec<String> = ["s_0", "a_1", "s_0_", "a_1_", "rhs"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        assert_eq!(tableau.column_names(), &column_names);
+        assert_eq!(
+            tableau.row_names(),
+            &vec!["s_0_".to_string(), "a_1_".to_string(), "z".to_string()]
+        );
+    }
+}
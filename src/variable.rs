@@ -0,0 +1,175 @@
+// Copyright 2024 Felix Kahle. All rights reserved.
+
+#![allow(dead_code)]
+
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::rc::Rc;
+
+/// A decision variable of a linear program.
+///
+/// # Note
+/// All variables are implicitly non-negative.
+/// Variables are identified by their name, so two variables with the same name are equal,
+/// even if they were created by separate calls to `Variable::new`.
+#[derive(Debug, Clone)]
+pub struct Variable {
+    /// The name of the variable.
+    /// The name is reference counted, so cloning a variable is cheap.
+    name: Rc<String>,
+}
+
+impl Variable {
+    /// Create a new Variable.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the variable.
+    ///
+    /// # Returns
+    /// A new Variable.
+    pub fn new(name: &str) -> Variable {
+        Variable {
+            name: Rc::new(name.to_string()),
+        }
+    }
+
+    /// Create a new Variable that is part of a numbered family.
+    ///
+    /// # Arguments
+    /// * `prefix` - The prefix of the name of the variable.
+    /// * `index` - The index of the variable within the family.
+    ///
+    /// # Returns
+    /// A new Variable named `{prefix}_{index}`.
+    pub fn with_prefix(prefix: &str, index: usize) -> Variable {
+        Variable::new(&format!("{}_{}", prefix, index))
+    }
+
+    /// Create a numbered family of variables.
+    ///
+    /// # Arguments
+    /// * `prefix` - The prefix of the names of the variables.
+    /// * `count` - The number of variables.
+    ///
+    /// # Returns
+    /// The variables `{prefix}_0` to `{prefix}_{count - 1}`.
+    pub fn family(prefix: &str, count: usize) -> Vec<Variable> {
+        (0..count)
+            .map(|index| Variable::with_prefix(prefix, index))
+            .collect()
+    }
+
+    /// Create a new Variable from an index.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the variable.
+    ///
+    /// # Returns
+    /// A new Variable named `x_{index}`.
+    pub fn from_index(index: usize) -> Variable {
+        Variable::with_prefix("x", index)
+    }
+
+    /// Create a numbered family of variables from a range of indices.
+    ///
+    /// # Arguments
+    /// * `prefix` - The prefix of the names of the variables.
+    /// * `range` - The indices of the variables.
+    ///
+    /// # Returns
+    /// The variables `{prefix}_{index}` for every index in the range.
+    pub fn family_from_range(prefix: &str, range: Range<usize>) -> Vec<Variable> {
+        range
+            .map(|index| Variable::with_prefix(prefix, index))
+            .collect()
+    }
+
+    /// Get the name of the variable.
+    ///
+    /// # Returns
+    /// The name of the variable.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Compare variables by the content of their names.
+///
+/// # Note
+/// The pointers of the reference counted names are never compared.
+impl PartialEq for Variable {
+    fn eq(&self, other: &Variable) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl Eq for Variable {}
+
+/// Hash variables by the content of their names, consistent with `PartialEq`.
+impl Hash for Variable {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
+/// Implement the Display trait for Variable.
+///
+/// # Note
+/// The variable is displayed by its name.
+impl Display for Variable {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn family_names_and_count() {
+        let family = Variable::family("x", 3);
+        assert_eq!(family.len(), 3);
+        let names: Vec<&str> = family.iter().map(|v| v.name()).collect();
+        assert_eq!(names, vec!["x_0", "x_1", "x_2"]);
+        assert_eq!(Variable::with_prefix("s", 7).name(), "s_7");
+        assert!(Variable::family("x", 0).is_empty());
+    }
+
+    #[test]
+    fn family_from_range_and_from_index() {
+        let family = Variable::family_from_range("y", 0..5);
+        assert_eq!(family.len(), 5);
+        assert_eq!(family[0].name(), "y_0");
+        assert_eq!(family[4].name(), "y_4");
+        assert_eq!(Variable::family_from_range("y", 2..4)[0].name(), "y_2");
+        assert_eq!(Variable::from_index(3).name(), "x_3");
+    }
+
+    fn hash_of(variable: &Variable) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        variable.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn variables_with_the_same_name_are_equal() {
+        let x = Variable::new("x");
+        let other_x = Variable::new("x");
+        assert_eq!(x, other_x);
+        assert_eq!(x, x.clone());
+        assert_eq!(hash_of(&x), hash_of(&other_x));
+        assert_ne!(x, Variable::new("y"));
+    }
+
+    #[test]
+    fn variables_as_hash_set_keys() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(Variable::new("x"));
+        set.insert(Variable::new("x"));
+        set.insert(Variable::new("y"));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Variable::new("x")));
+    }
+}